@@ -0,0 +1,58 @@
+//! Every solver on its real input, or on the example of the puzzle when
+//! there is none, and on synthetic inputs growing in size.
+//!
+//! `cargo bench -p aoc -- --save-baseline main` records the results of a
+//! branch, `cargo bench -p aoc -- --baseline main` compares with them.
+
+use aoc::solvers;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::fs;
+use std::time::Duration;
+
+mod synthetic;
+
+fn bench_solvers(c: &mut Criterion) {
+    let solvers = solvers::all();
+    let mut days: Vec<u8> = solvers.iter().map(|solver| solver.day).collect();
+    days.dedup();
+
+    for day in days {
+        let mut group = c.benchmark_group(format!("day_{:02}", day));
+        let mut day_solvers = solvers.iter().filter(|solver| solver.day == day).peekable();
+        let example = day_solvers.peek().map_or("", |solver| solver.example);
+        let (name, input) = match fs::read_to_string(solvers::input_path(day)) {
+            Ok(input) => ("real", input),
+            Err(_) => ("example", example.to_string()),
+        };
+        let synthetic: Vec<(usize, String)> = synthetic::sizes(day)
+            .iter()
+            .filter_map(|&size| Some((size, synthetic::generate(day, size)?)))
+            .collect();
+
+        for solver in day_solvers {
+            let part = format!("part{}", solver.part);
+            group.throughput(Throughput::Bytes(input.len() as u64));
+            group.bench_with_input(BenchmarkId::new(&part, name), &input, |b, input| {
+                b.iter(|| solver.run(input).unwrap())
+            });
+            for (size, input) in &synthetic {
+                group.throughput(Throughput::Bytes(input.len() as u64));
+                group.bench_with_input(BenchmarkId::new(&part, size), input, |b, input| {
+                    b.iter(|| solver.run(input).unwrap())
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // Some parts take seconds, keep a run of the whole suite short
+    config = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500))
+        .measurement_time(Duration::from_secs(2));
+    targets = bench_solvers
+}
+criterion_main!(benches);
@@ -0,0 +1,634 @@
+//! Scaled-up puzzle inputs, generated from a fixed seed so every run and
+//! every branch benchmarks the same data.
+
+use aoc_common::Snafu;
+use fastrand::Rng;
+
+const SEED: u64 = 2022;
+
+/// Sizes of the synthetic inputs of a day, in items of its input.
+pub fn sizes(day: u8) -> &'static [usize] {
+    match day {
+        1 => &[10_000, 100_000],
+        2 | 4 | 9 => &[10_000, 100_000],
+        3 => &[3_000, 30_000],
+        5 | 10 | 25 => &[10_000, 100_000],
+        6 => &[40_000, 400_000],
+        7 => &[300, 3_000],
+        8 => &[200, 400],
+        12 => &[100, 300],
+        13 => &[1_000, 10_000],
+        14 => &[150, 400],
+        15 => &[10_000, 100_000],
+        16 => &[15, 20],
+        17 => &[1_000, 10_000],
+        18 => &[20, 40],
+        19 => &[30, 60],
+        20 => &[2_500, 5_000],
+        21 => &[10_000, 100_000],
+        22 => &[50, 200],
+        23 => &[35, 70],
+        24 => &[40, 120],
+        _ => &[],
+    }
+}
+
+/// Synthetic input of `day` with `size` items: elves, rounds, rucksacks,
+/// pairs, moves, characters, directories, grid side, instructions, map
+/// side, packet pairs, cave depth, rows to scan, valves to open, jets,
+/// droplet side, blueprints, numbers, yelling monkeys, cube face side,
+/// grove side, valley width or SNAFU numbers.
+/// `None` for the days without a generator, see `BENCHMARKS.md`.
+pub fn generate(day: u8, size: usize) -> Option<String> {
+    let mut rng = Rng::with_seed(SEED);
+    let input = match day {
+        1 => calories(&mut rng, size),
+        2 => rounds(&mut rng, size),
+        3 => rucksacks(&mut rng, size),
+        4 => pairs(&mut rng, size),
+        5 => crates(&mut rng, size),
+        6 => datastream(&mut rng, size),
+        7 => terminal(&mut rng, size),
+        8 => trees(&mut rng, size),
+        9 => moves(&mut rng, size),
+        10 => program(&mut rng, size),
+        12 => heightmap(&mut rng, size),
+        13 => packets(&mut rng, size),
+        14 => rocks(&mut rng, size),
+        15 => sensors(size),
+        16 => valves(&mut rng, size),
+        17 => jets(&mut rng, size),
+        18 => droplet(&mut rng, size),
+        19 => blueprints(&mut rng, size),
+        20 => numbers(&mut rng, size),
+        21 => troop(&mut rng, size),
+        22 => board(&mut rng, size),
+        23 => grove(&mut rng, size),
+        24 => valley(&mut rng, size),
+        25 => snafus(&mut rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn calories(rng: &mut Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            (0..rng.usize(1..15))
+                .map(|_| format!("{}\n", rng.u32(1000..60000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn rounds(rng: &mut Rng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choice(['A', 'B', 'C']).unwrap(),
+                rng.choice(['X', 'Y', 'Z']).unwrap()
+            )
+        })
+        .collect()
+}
+
+fn item(rng: &mut Rng) -> char {
+    if rng.bool() {
+        rng.char('a'..='z')
+    } else {
+        rng.char('A'..='Z')
+    }
+}
+
+/// Groups of three rucksacks sharing a badge, each with an item in both
+/// compartments.
+fn rucksacks(rng: &mut Rng, rucksacks: usize) -> String {
+    let mut output = String::new();
+    for _ in 0..rucksacks / 3 {
+        let badge = item(rng);
+        for _ in 0..3 {
+            let half = rng.usize(8..20);
+            let common = item(rng);
+            let mut first: Vec<char> = (0..half - 2).map(|_| item(rng)).collect();
+            first.push(common);
+            first.push(badge);
+            let mut second: Vec<char> = (0..half - 1).map(|_| item(rng)).collect();
+            second.push(common);
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+            output.extend(first.iter().chain(second.iter()));
+            output.push('\n');
+        }
+    }
+    output
+}
+
+fn pairs(rng: &mut Rng, pairs: usize) -> String {
+    let range = |rng: &mut Rng| {
+        let start = rng.u32(1..100);
+        (start, rng.u32(start..100))
+    };
+    (0..pairs)
+        .map(|_| {
+            let (a, b) = range(rng);
+            let (c, d) = range(rng);
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
+
+/// Nine stacks of crates, and moves always leaving a crate on their source.
+fn crates(rng: &mut Rng, moves: usize) -> String {
+    const STACKS: usize = 9;
+    let mut heights: Vec<usize> = (0..STACKS).map(|_| rng.usize(5..20)).collect();
+    let top = *heights.iter().max().unwrap();
+    let mut output = String::new();
+    for level in (0..top).rev() {
+        let line = heights
+            .iter()
+            .map(|&height| {
+                if level < height {
+                    format!("[{}]", rng.char('A'..='Z'))
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .collect::<Vec<String>>();
+        output.push_str(&line.join(" "));
+        output.push('\n');
+    }
+    let numbers = (1..=STACKS)
+        .map(|stack| format!(" {} ", stack))
+        .collect::<Vec<String>>();
+    output.push_str(&numbers.join(" "));
+    output.push_str("\n\n");
+    for _ in 0..moves {
+        let src = loop {
+            let src = rng.usize(0..STACKS);
+            if heights[src] > 1 {
+                break src;
+            }
+        };
+        let dst = (src + rng.usize(1..STACKS)) % STACKS;
+        let nb = rng.usize(1..heights[src].min(10));
+        heights[src] -= nb;
+        heights[dst] += nb;
+        output.push_str(&format!("move {} from {} to {}\n", nb, src + 1, dst + 1));
+    }
+    output
+}
+
+/// Characters repeating too often for a marker, which only comes at the end.
+fn datastream(rng: &mut Rng, characters: usize) -> String {
+    let mut output: String = (0..characters).map(|_| rng.char('a'..='m')).collect();
+    output.push_str("abcdefghijklmnopqrstuvwxyz\n");
+    output
+}
+
+/// Browsing a random tree of `directories`, holding more than the 40000000
+/// left by the update but less than the 70000000 of the device.
+fn terminal(rng: &mut Rng, directories: usize) -> String {
+    fn browse(rng: &mut Rng, children: &[Vec<usize>], dir: usize, output: &mut String) {
+        output.push_str("$ ls\n");
+        for &child in &children[dir] {
+            output.push_str(&format!("dir d{}\n", child));
+        }
+        for file in 0..rng.usize(1..4) {
+            let size = rng.u32(1..110_000_000 / (children.len() as u32 * 2));
+            output.push_str(&format!("{} f{}.txt\n", size, file));
+        }
+        for &child in &children[dir] {
+            output.push_str(&format!("$ cd d{}\n", child));
+            browse(rng, children, child, output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut children = vec![Vec::new(); directories];
+    for dir in 1..directories {
+        children[rng.usize(0..dir)].push(dir);
+    }
+    let mut output = "$ cd /\n".to_string();
+    browse(rng, &children, 0, &mut output);
+    output
+}
+
+fn trees(rng: &mut Rng, side: usize) -> String {
+    (0..side)
+        .map(|_| {
+            let mut row: String = (0..side).map(|_| rng.char('0'..='9')).collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+fn moves(rng: &mut Rng, moves: usize) -> String {
+    (0..moves)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choice(['U', 'D', 'L', 'R']).unwrap(),
+                rng.u32(1..20)
+            )
+        })
+        .collect()
+}
+
+/// Instructions keeping the register within the 40 pixels of a row.
+fn program(rng: &mut Rng, instructions: usize) -> String {
+    let mut register = 1;
+    (0..instructions)
+        .map(|_| {
+            if rng.u8(0..3) == 0 {
+                return "noop\n".to_string();
+            }
+            let value = rng.i32(-register..40 - register);
+            register += value;
+            format!("addx {}\n", value)
+        })
+        .collect()
+}
+
+/// Elevations growing from the start `S` in a corner to the end `E` in the
+/// other one, with pits at `a` all over.
+fn heightmap(rng: &mut Rng, side: usize) -> String {
+    (0..side)
+        .map(|y| {
+            let mut row: String = (0..side)
+                .map(|x| match (x, y) {
+                    (0, 0) => 'S',
+                    _ if x == side - 1 && y == side - 1 => 'E',
+                    _ if x < side - 1 && y < side - 1 && rng.u8(0..10) == 0 => 'a',
+                    _ => (b'a' + ((x + y) * 25 / (2 * side - 2)) as u8) as char,
+                })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+fn items(rng: &mut Rng, depth: usize) -> Vec<String> {
+    (0..rng.usize(0..5))
+        .map(|_| {
+            if depth < 4 && rng.bool() {
+                format!("[{}]", items(rng, depth + 1).join(","))
+            } else {
+                rng.u8(0..11).to_string()
+            }
+        })
+        .collect()
+}
+
+/// Pairs of packets never equal, as their first items differ.
+fn packets(rng: &mut Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| {
+            let left = rng.u8(0..10);
+            let right = rng.u8(left + 1..11);
+            let (left, right) = if rng.bool() {
+                (left, right)
+            } else {
+                (right, left)
+            };
+            let packet = |rng: &mut Rng, first: u8| {
+                let mut items = items(rng, 1);
+                items.insert(0, first.to_string());
+                format!("[{}]\n", items.join(","))
+            };
+            packet(rng, left) + &packet(rng, right)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Rock paths below the source of sand, from an eighth of `depth` down to it.
+fn rocks(rng: &mut Rng, depth: usize) -> String {
+    let depth = depth as i32;
+    (0..depth)
+        .map(|_| {
+            let mut x = rng.i32(500 - depth / 2..500 + depth / 2);
+            let mut y = rng.i32(depth / 8..=depth);
+            let mut points = vec![format!("{},{}", x, y)];
+            for segment in 0..rng.usize(1..5) {
+                if segment % 2 == 0 {
+                    x = (x + rng.i32(-10..=10)).clamp(500 - depth, 500 + depth);
+                } else {
+                    y = (y + rng.i32(-10..=10)).clamp(depth / 8, depth);
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// A lattice of sensors covering every row, but for a single cell on row
+/// `rows`, as the four sensors around it reach one cell less far, and a
+/// line of sensors on the row of the first part.
+fn sensors(rows: usize) -> String {
+    const SPACING: isize = 1_000;
+    let rows = (rows as isize / SPACING).max(1);
+    let (hole_x, hole_y) = (1, rows - 1);
+    let lines = (-1..=rows + 1).chain([day_15::Y_TO_FIND / SPACING]);
+    let mut output = String::new();
+    for x in 0..4 {
+        for y in lines.clone() {
+            let around = (hole_x..=hole_x + 1).contains(&x) && (hole_y..=hole_y + 1).contains(&y);
+            let reach = if around { SPACING - 1 } else { SPACING };
+            let (x, y) = (x * SPACING, y * SPACING);
+            output.push_str(&format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x,
+                y,
+                x + reach,
+                y
+            ));
+        }
+    }
+    output
+}
+
+/// A ring of valves, with shortcuts, where `valves` of them can be opened.
+fn valves(rng: &mut Rng, valves: usize) -> String {
+    let count = valves * 2 + 1;
+    let name = |index: usize| -> String {
+        let index = index as u8;
+        [b'A' + index / 26, b'A' + index % 26]
+            .iter()
+            .map(|&b| b as char)
+            .collect()
+    };
+    let mut flow_rates = vec![0; count];
+    let mut indexes: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut indexes);
+    for &index in &indexes[..valves] {
+        flow_rates[index] = rng.u32(1..25);
+    }
+    let mut tunnels: Vec<Vec<usize>> = (0..count)
+        .map(|index| vec![(index + 1) % count, (index + count - 1) % count])
+        .collect();
+    for _ in 0..valves / 2 {
+        let (a, b) = (rng.usize(0..count), rng.usize(0..count));
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    }
+    (0..count)
+        .map(|index| {
+            let names = tunnels[index]
+                .iter()
+                .map(|&t| name(t))
+                .collect::<Vec<String>>();
+            format!(
+                "Valve {} has flow rate={}; tunnels lead to valves {}\n",
+                name(index),
+                flow_rates[index],
+                names.join(", ")
+            )
+        })
+        .collect()
+}
+
+fn jets(rng: &mut Rng, jets: usize) -> String {
+    let mut output: String = (0..jets)
+        .map(|_| if rng.bool() { '<' } else { '>' })
+        .collect();
+    output.push('\n');
+    output
+}
+
+/// Cubes filling a third of a box of `side`.
+fn droplet(rng: &mut Rng, side: usize) -> String {
+    let side = side as u32;
+    (0..side.pow(3) / 3)
+        .map(|_| {
+            format!(
+                "{},{},{}\n",
+                rng.u32(0..side),
+                rng.u32(0..side),
+                rng.u32(0..side)
+            )
+        })
+        .collect()
+}
+
+/// Blueprints with robot costs in the ranges of the puzzle inputs.
+fn blueprints(rng: &mut Rng, blueprints: usize) -> String {
+    (1..=blueprints)
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.\n",
+                id,
+                rng.u32(2..=4),
+                rng.u32(2..=4),
+                rng.u32(2..=4),
+                rng.u32(5..=20),
+                rng.u32(2..=4),
+                rng.u32(7..=20)
+            )
+        })
+        .collect()
+}
+
+/// Numbers with a single 0.
+fn numbers(rng: &mut Rng, numbers: usize) -> String {
+    let mut numbers: Vec<i64> = (1..numbers)
+        .map(|_| {
+            let n = rng.i64(1..10_000);
+            if rng.bool() {
+                n
+            } else {
+                -n
+            }
+        })
+        .collect();
+    numbers.insert(rng.usize(0..=numbers.len()), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+/// Monkeys of a random tree, yelling numbers other than 0, with exact
+/// divisions.
+struct Troop {
+    lines: Vec<String>,
+    names: usize,
+}
+
+impl Troop {
+    fn name(&mut self) -> String {
+        loop {
+            let mut n = self.names;
+            self.names += 1;
+            let name: String = (0..4)
+                .map(|_| {
+                    let c = (b'a' + (n % 26) as u8) as char;
+                    n /= 26;
+                    c
+                })
+                .collect();
+            if name != "root" && name != "humn" {
+                return name;
+            }
+        }
+    }
+
+    fn yell(&mut self, n: i64) -> String {
+        let name = self.name();
+        self.lines.push(format!("{}: {}", name, n));
+        name
+    }
+
+    fn operation(&mut self, a: &str, op: char, b: &str) -> String {
+        let name = self.name();
+        self.lines.push(format!("{}: {} {} {}", name, a, op, b));
+        name
+    }
+
+    /// Monkey waiting for `leaves` yelling ones, one of them the human if
+    /// `human`, and its number.
+    fn tree(&mut self, rng: &mut Rng, leaves: usize, human: bool) -> (String, i64) {
+        if leaves <= 1 {
+            if human {
+                let n = rng.i64(1..10);
+                self.lines.push(format!("humn: {}", n));
+                return ("humn".to_string(), n);
+            }
+            let n = rng.i64(1..10);
+            return (self.yell(n), n);
+        }
+        let left = rng.usize(1..leaves);
+        let human_left = human && rng.bool();
+        let (a, x) = self.tree(rng, left, human_left);
+        let (b, y) = self.tree(rng, leaves - left, human && !human_left);
+        let (name, n) = if (rng.bool() && x + y != 0) || x == y {
+            (self.operation(&a, '+', &b), x + y)
+        } else {
+            (self.operation(&a, '-', &b), x - y)
+        };
+        match rng.u8(0..8) {
+            0 => {
+                let factor = rng.i64(2..5);
+                let b = self.yell(factor);
+                (self.operation(&name, '*', &b), n * factor)
+            }
+            1 => {
+                let divisor = rng.i64(2..5);
+                let (b, c) = (self.yell(divisor), self.yell(divisor));
+                let product = self.operation(&name, '*', &b);
+                (self.operation(&product, '/', &c), n)
+            }
+            _ => (name, n),
+        }
+    }
+}
+
+/// Two trees of `leaves` monkeys yelling numbers, the number of the human
+/// making both sides of `root` equal.
+fn troop(rng: &mut Rng, leaves: usize) -> String {
+    let mut troop = Troop {
+        lines: Vec::new(),
+        names: 0,
+    };
+    let (a, x) = troop.tree(rng, leaves / 2, true);
+    let (b, y) = troop.tree(rng, leaves - leaves / 2, false);
+    let difference = troop.yell(y - x);
+    let a = troop.operation(&a, '+', &difference);
+    troop.lines.push(format!("root: {} + {}", a, b));
+    rng.shuffle(&mut troop.lines);
+    troop
+        .lines
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+/// The net of the example with faces of `side` tiles, a few walls, and a
+/// path of a thousand steps.
+fn board(rng: &mut Rng, side: usize) -> String {
+    const NET: [&str; 3] = ["  X ", "XXX ", "  XX"];
+    let mut output = String::new();
+    for faces in NET {
+        for _ in 0..side {
+            let row: String = faces
+                .trim_end()
+                .chars()
+                .flat_map(|face| {
+                    let tiles: Vec<char> = (0..side)
+                        .map(|_| match face {
+                            ' ' => ' ',
+                            _ if rng.u8(0..10) == 0 => '#',
+                            _ => '.',
+                        })
+                        .collect();
+                    tiles
+                })
+                .collect();
+            output.push_str(&row);
+            output.push('\n');
+        }
+    }
+    output.push('\n');
+    for _ in 0..1_000 {
+        let turn = if rng.bool() { 'R' } else { 'L' };
+        output.push_str(&format!("{}{}", rng.usize(1..=side), turn));
+    }
+    output.push_str(&format!("{}\n", rng.usize(1..=side)));
+    output
+}
+
+/// Elves on half of the tiles of a square of `side`.
+fn grove(rng: &mut Rng, side: usize) -> String {
+    (0..side)
+        .map(|_| {
+            let mut row: String = (0..side)
+                .map(|_| if rng.bool() { '#' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+/// A valley of `width` and a quarter as high, blizzards on a third of its
+/// tiles, none going up or down the columns of the entrance and the exit.
+fn valley(rng: &mut Rng, width: usize) -> String {
+    let height = (width / 4).max(1);
+    let wall = |gap: usize| -> String {
+        (0..width + 2)
+            .map(|x| if x == gap { '.' } else { '#' })
+            .collect::<String>()
+            + "\n"
+    };
+    let mut output = wall(1);
+    for _ in 0..height {
+        output.push('#');
+        for x in 1..=width {
+            let blizzards: &[char] = if x == 1 || x == width {
+                &['<', '>']
+            } else {
+                &['<', '>', '^', 'v']
+            };
+            let tile = if rng.u8(0..3) == 0 {
+                blizzards[rng.usize(0..blizzards.len())]
+            } else {
+                '.'
+            };
+            output.push(tile);
+        }
+        output.push_str("#\n");
+    }
+    output.push_str(&wall(width));
+    output
+}
+
+fn snafus(rng: &mut Rng, numbers: usize) -> String {
+    (0..numbers)
+        .map(|_| format!("{}\n", Snafu(rng.i64(1..1_000_000_000_000))))
+        .collect()
+}
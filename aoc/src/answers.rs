@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Accepted answers of the real puzzle inputs, by day and part.
+///
+/// Days are `[day_NN]` tables holding `part1` and `part2` strings.
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, String>>);
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map(Self).map_err(|e| e.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Self::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.0
+            .get(&format!("day_{:02}", day))?
+            .get(&format!("part{}", part))
+            .map(|answer| answer.as_str())
+    }
+}
+
+/// The answers.toml file at the workspace root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("answers.toml")
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(indoc!(
+            r#"
+            [day_09]
+            part1 = "5902"
+            part2 = "2445"
+
+            [day_10]
+            part2 = """
+            ##..
+            .##."""
+            "#
+        ))
+        .unwrap();
+        assert_eq!(answers.get(9, 2), Some("2445"));
+        assert_eq!(answers.get(10, 2), Some("##..\n.##."));
+        assert_eq!(answers.get(10, 1), None);
+        assert_eq!(answers.get(11, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("[day_09]\npart1 = 5902\n").is_err());
+    }
+
+    #[test]
+    fn test_load_default() {
+        let answers = Answers::load(&default_path()).unwrap();
+        assert_eq!(answers.get(1, 1), Some("69693"));
+    }
+}
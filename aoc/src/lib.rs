@@ -0,0 +1,3 @@
+//! Solvers of every day, shared by the runner and the benchmarks.
+
+pub mod solvers;
@@ -0,0 +1,392 @@
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc_common::{input, SolveError};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+
+mod answers;
+
+use answers::Answers;
+use aoc::solvers::{self, Solver};
+
+#[derive(Parser)]
+#[command(about = "Run the advent of code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show debug diagnostics, `-vv` for trace ones. `RUST_LOG` takes precedence
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of one day, or of every day
+    Run {
+        /// Day to run
+        #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        /// Part to run, both parts are run if not set
+        #[arg(long, requires = "day")]
+        part: Option<u8>,
+        /// Puzzle input, `-` for stdin, defaults to the input.txt of the day
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Run every day and part
+        #[arg(long)]
+        all: bool,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Compare the answers on the real inputs with the ones in answers.toml
+    Check {
+        /// Day to check, every day is checked if not set
+        #[arg(long)]
+        day: Option<u8>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Aligned table for humans
+    Table,
+    /// JSON array of `{day, part, answer, elapsed_ns}` objects
+    Json,
+}
+
+struct Outcome {
+    day: u8,
+    part: u8,
+    answer: String,
+    elapsed: Duration,
+}
+
+fn read_input(solver: &Solver, input: &Option<PathBuf>) -> io::Result<String> {
+    match input {
+        Some(path) => input::read_path(path),
+        None => input::read_default(&solvers::input_path(solver.day), solver.example),
+    }
+}
+
+fn solve(solver: &Solver, input: &str) -> Result<Outcome, SolveError> {
+    let start = Instant::now();
+    let answer = solver.run(input)?;
+    let elapsed = start.elapsed();
+
+    Ok(Outcome {
+        day: solver.day,
+        part: solver.part,
+        answer,
+        elapsed,
+    })
+}
+
+fn format_table(outcomes: &[Outcome]) -> String {
+    let answer_width = outcomes
+        .iter()
+        .flat_map(|o| o.answer.lines())
+        .map(|line| line.chars().count())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .unwrap();
+
+    let mut lines = vec![format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>12}",
+        "Day", "Part", "Answer", "Time"
+    )];
+    for outcome in outcomes {
+        // Multi-line answers (day 10 CRT) are continued on the following rows
+        let mut answer = outcome.answer.lines();
+        lines.push(format!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>12}",
+            outcome.day,
+            outcome.part,
+            answer.next().unwrap_or(""),
+            format!("{:.2?}", outcome.elapsed)
+        ));
+        for line in answer {
+            lines.push(format!("{:>3}  {:>4}  {}", "", "", line));
+        }
+    }
+    lines
+        .iter()
+        .map(|line| line.trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[derive(Serialize)]
+struct JsonOutcome<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ns: u128,
+}
+
+fn format_json(outcomes: &[Outcome]) -> String {
+    let outcomes: Vec<JsonOutcome> = outcomes
+        .iter()
+        .map(|o| JsonOutcome {
+            day: o.day,
+            part: o.part,
+            answer: &o.answer,
+            elapsed_ns: o.elapsed.as_nanos(),
+        })
+        .collect();
+    serde_json::to_string_pretty(&outcomes).unwrap()
+}
+
+fn select(day: Option<u8>, part: Option<u8>) -> Result<Vec<Solver>, String> {
+    let selected = match day {
+        None => solvers::all(),
+        Some(day) => solvers::find(day, part),
+    };
+    if selected.is_empty() {
+        return Err(match part {
+            None => format!("No solution for day {}", day.unwrap()),
+            Some(part) => format!("No solution for day {} part {}", day.unwrap(), part),
+        });
+    }
+    Ok(selected)
+}
+
+fn run(
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+) -> Result<String, String> {
+    let selected = select(day, part)?;
+
+    let mut outcomes = Vec::new();
+    for solver in &selected {
+        let input = read_input(solver, &input)
+            .map_err(|e| format!("Cannot read day {} input: {}", solver.day, e))?;
+        let outcome = solve(solver, &input)
+            .map_err(|e| format!("Day {} part {}: {}", solver.day, solver.part, e))?;
+        outcomes.push(outcome);
+    }
+    Ok(match format {
+        Format::Table => format_table(&outcomes),
+        Format::Json => format_json(&outcomes),
+    })
+}
+
+/// Status of one part against its known answer, on the input at
+/// `input_path(day)`.
+fn check_solver(
+    solver: &Solver,
+    answers: &Answers,
+    input_path: fn(u8) -> PathBuf,
+) -> Result<String, String> {
+    let input_path = input_path(solver.day);
+    if !input_path.exists() {
+        return Ok("skipped, no input.txt".to_string());
+    }
+    let Some(expected) = answers.get(solver.day, solver.part) else {
+        return Ok("skipped, no known answer".to_string());
+    };
+    let input = input::read_path(&input_path)
+        .map_err(|e| format!("Cannot read day {} input: {}", solver.day, e))?;
+    let outcome = solve(solver, &input).map_err(|e| e.to_string())?;
+    // Multi-line answers may end with a line feed
+    if outcome.answer.trim_end() == expected.trim_end() {
+        Ok(format!("ok  {:.2?}", outcome.elapsed))
+    } else {
+        Err(format!(
+            "expected {:?}, got {:?}",
+            expected.trim_end(),
+            outcome.answer.trim_end()
+        ))
+    }
+}
+
+fn check(
+    day: Option<u8>,
+    answers: &Answers,
+    input_path: fn(u8) -> PathBuf,
+) -> Result<String, String> {
+    let selected = select(day, None)?;
+
+    let mut lines = vec![format!("{:>3}  {:>4}  {}", "Day", "Part", "Status")];
+    let mut nb_failed = 0;
+    for solver in &selected {
+        let status = check_solver(solver, answers, input_path).unwrap_or_else(|e| {
+            nb_failed += 1;
+            format!("FAILED, {}", e)
+        });
+        lines.push(format!("{:>3}  {:>4}  {}", solver.day, solver.part, status));
+    }
+
+    let report = lines.join("\n");
+    if nb_failed > 0 {
+        Err(format!("{}\n{} answers differ", report, nb_failed))
+    } else {
+        Ok(report)
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    aoc_common::init_tracing(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            ..
+        } => run(day, part, input, format),
+        Command::Check { day } => Answers::load(&answers::default_path())
+            .and_then(|answers| check(day, &answers, solvers::input_path)),
+    };
+
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_format_table() {
+        let outcomes = vec![
+            Outcome {
+                day: 9,
+                part: 2,
+                answer: "2445".to_string(),
+                elapsed: Duration::from_micros(1500),
+            },
+            Outcome {
+                day: 10,
+                part: 2,
+                answer: "##..\n.##.".to_string(),
+                elapsed: Duration::from_nanos(800),
+            },
+        ];
+        assert_eq!(
+            format_table(&outcomes),
+            indoc!(
+                "
+                Day  Part  Answer          Time
+                  9     2  2445          1.50ms
+                 10     2  ##..        800.00ns
+                           .##."
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_json() {
+        let outcomes = vec![Outcome {
+            day: 10,
+            part: 2,
+            answer: "##..\n.##.".to_string(),
+            elapsed: Duration::from_micros(1500),
+        }];
+        assert_eq!(
+            format_json(&outcomes),
+            indoc!(
+                r###"
+                [
+                  {
+                    "day": 10,
+                    "part": 2,
+                    "answer": "##..\n.##.",
+                    "elapsed_ns": 1500000
+                  }
+                ]"###
+            )
+        );
+    }
+
+    /// Day 1 example, so that the tests do not depend on the real inputs.
+    fn fixture(_day: u8) -> PathBuf {
+        PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/day_01.txt"))
+    }
+
+    #[test]
+    fn test_run_json() {
+        let output = run(Some(1), Some(1), Some(fixture(1)), Format::Json).unwrap();
+        let outcomes: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(outcomes[0]["day"], 1);
+        assert_eq!(outcomes[0]["part"], 1);
+        assert_eq!(outcomes[0]["answer"], "24000");
+        assert!(outcomes[0]["elapsed_ns"].is_u64());
+    }
+
+    #[test]
+    fn test_run_unknown_day() {
+        assert_eq!(
+            run(Some(26), None, None, Format::Table),
+            Err("No solution for day 26".to_string())
+        );
+        assert_eq!(
+            run(Some(9), Some(3), None, Format::Table),
+            Err("No solution for day 9 part 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_input() {
+        let input = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+        assert_eq!(
+            run(Some(9), Some(1), Some(input), Format::Table),
+            Err(
+                "Day 9 part 1: line 1, column 1: unexpected `[package]`, expected `U`, `D`, `L` or `R`"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[day_01]\npart1 = \"24000\"\n").unwrap();
+        let report = check(Some(1), &answers, fixture).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+        assert!(lines[1].starts_with("  1     1  ok  "));
+        assert_eq!(lines[2], "  1     2  skipped, no known answer");
+    }
+
+    #[test]
+    fn test_check_wrong_answer() {
+        let answers = Answers::parse("[day_01]\npart1 = \"69693\"\n").unwrap();
+        assert_eq!(
+            check(Some(1), &answers, fixture),
+            Err(indoc!(
+                "
+                Day  Part  Status
+                  1     1  FAILED, expected \"69693\", got \"24000\"
+                  1     2  skipped, no known answer
+                1 answers differ"
+            )
+            .to_string())
+        );
+    }
+
+    /// Every known answer, slow in debug builds: run with
+    /// `cargo test --release -p aoc -- --ignored`.
+    #[test]
+    #[ignore]
+    fn test_check_known_answers() {
+        let answers = Answers::load(&answers::default_path()).unwrap();
+        if let Err(report) = check(None, &answers, solvers::input_path) {
+            panic!("{}", report);
+        }
+    }
+}
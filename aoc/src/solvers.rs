@@ -0,0 +1,129 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::{Solution, SolveError};
+
+/// Every solved day, in order.
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &day_01::Day01,
+    &day_02::Day02,
+    &day_03::Day03,
+    &day_04::Day04,
+    &day_05::Day05,
+    &day_06::Day06,
+    &day_07::Day07,
+    &day_08::Day08,
+    &day_09::Day09,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+/// One part of a day.
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    pub example: &'static str,
+    solution: &'static dyn Solution,
+}
+
+impl Solver {
+    pub fn run(&self, input: &str) -> Result<String, SolveError> {
+        self.solution
+            .solve(self.part, input)
+            .expect("solver built from the solution parts")
+            .map(|answer| answer.to_string())
+    }
+}
+
+/// Every part of every day.
+pub fn all() -> Vec<Solver> {
+    SOLUTIONS
+        .iter()
+        .flat_map(|&solution| {
+            solution.parts().iter().map(move |&part| Solver {
+                day: solution.day(),
+                part,
+                example: solution.example(),
+                solution,
+            })
+        })
+        .collect()
+}
+
+/// The real puzzle input of a day.
+pub fn input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{:02}", day))
+        .join("input.txt")
+}
+
+pub fn find(day: u8, part: Option<u8>) -> Vec<Solver> {
+    all()
+        .into_iter()
+        .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part))
+        .collect()
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(9, None).len(), 2);
+        assert_eq!(find(9, Some(2))[0].part, 2);
+        assert_eq!(find(9, Some(3)).len(), 0);
+        assert_eq!(find(26, None).len(), 0);
+    }
+
+    #[test]
+    fn test_run() {
+        let input = indoc!(
+            "
+            1000
+            2000
+            3000
+
+            4000
+
+            5000
+            6000
+
+            7000
+            8000
+            9000
+
+            10000
+            "
+        );
+        assert_eq!(find(1, Some(1))[0].run(input), Ok("24000".to_string()));
+        assert_eq!(find(1, Some(2))[0].run(input), Ok("45000".to_string()));
+    }
+
+    #[test]
+    fn test_run_invalid_input() {
+        let Err(SolveError::Parse(error)) = find(9, Some(1))[0].run("R 4\nX 2\n") else {
+            panic!("expected a parse error");
+        };
+        assert_eq!(error.line, 2);
+        assert_eq!(error.token, "X");
+    }
+}
@@ -0,0 +1,381 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point of a 2D grid, x growing to the right and y downward.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Coord {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Coord {
+    pub fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn move_down(&mut self) {
+        *self += Direction::Down.vector();
+    }
+
+    pub fn move_left(&mut self) {
+        *self += Direction::Left.vector();
+    }
+
+    pub fn move_right(&mut self) {
+        *self += Direction::Right.vector();
+    }
+
+    /// Manhattan distance between two coordinates.
+    pub fn mdist(&self, other: &Coord) -> usize {
+        ((other.x - self.x).abs() + (other.y - self.y).abs())
+            .try_into()
+            .unwrap()
+    }
+
+    /// Chebyshev distance between two coordinates: the number of king moves
+    /// from one to the other.
+    pub fn chebyshev(&self, other: &Coord) -> usize {
+        (other.x - self.x)
+            .abs()
+            .max((other.y - self.y).abs())
+            .try_into()
+            .unwrap()
+    }
+
+    /// Euclidean distance between two coordinates.
+    pub fn euclidean(&self, other: &Coord) -> f64 {
+        let Vector2 { x, y } = *other - *self;
+        ((x * x + y * y) as f64).sqrt()
+    }
+}
+
+impl From<(isize, isize)> for Coord {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A move between two points of a 2D grid.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Vector2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Vector2 {
+    pub const N: Vector2 = Vector2::new(0, -1);
+    pub const NE: Vector2 = Vector2::new(1, -1);
+    pub const E: Vector2 = Vector2::new(1, 0);
+    pub const SE: Vector2 = Vector2::new(1, 1);
+    pub const S: Vector2 = Vector2::new(0, 1);
+    pub const SW: Vector2 = Vector2::new(-1, 1);
+    pub const W: Vector2 = Vector2::new(-1, 0);
+    pub const NW: Vector2 = Vector2::new(-1, -1);
+
+    /// The 8 one step moves, diagonals included, clockwise from the top
+    /// left.
+    pub const AROUND: [Vector2; 8] = [
+        Vector2::NW,
+        Vector2::N,
+        Vector2::NE,
+        Vector2::E,
+        Vector2::SE,
+        Vector2::S,
+        Vector2::SW,
+        Vector2::W,
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// The vector made of unit steps towards the same quadrant.
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl From<(isize, isize)> for Vector2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add<Vector2> for Coord {
+    type Output = Coord;
+
+    fn add(self, vector: Vector2) -> Coord {
+        Coord::new(self.x + vector.x, self.y + vector.y)
+    }
+}
+
+impl AddAssign<Vector2> for Coord {
+    fn add_assign(&mut self, vector: Vector2) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector2> for Coord {
+    type Output = Coord;
+
+    fn sub(self, vector: Vector2) -> Coord {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector2> for Coord {
+    fn sub_assign(&mut self, vector: Vector2) {
+        *self = *self - vector;
+    }
+}
+
+/// The vector going from `other` to `self`.
+impl Sub for Coord {
+    type Output = Vector2;
+
+    fn sub(self, other: Coord) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        self + -other
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, k: isize) -> Vector2 {
+        Vector2::new(self.x * k, self.y * k)
+    }
+}
+
+/// The 4 directions of a grid, clockwise from the top.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A one step move in this direction, up being towards smaller y.
+    pub fn vector(self) -> Vector2 {
+        match self {
+            Direction::Up => Vector2::N,
+            Direction::Right => Vector2::E,
+            Direction::Down => Vector2::S,
+            Direction::Left => Vector2::W,
+        }
+    }
+}
+
+/// A point of a 3D grid, or the move between two of them.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Coord3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Coord3 {
+    pub fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The 6 coordinates sharing a face with this one.
+    pub fn neighbours(&self) -> [Coord3; 6] {
+        [
+            Coord3::new(self.x - 1, self.y, self.z),
+            Coord3::new(self.x + 1, self.y, self.z),
+            Coord3::new(self.x, self.y - 1, self.z),
+            Coord3::new(self.x, self.y + 1, self.z),
+            Coord3::new(self.x, self.y, self.z - 1),
+            Coord3::new(self.x, self.y, self.z + 1),
+        ]
+    }
+
+    /// Manhattan distance between two coordinates.
+    pub fn mdist(&self, other: &Coord3) -> usize {
+        ((other.x - self.x).abs() + (other.y - self.y).abs() + (other.z - self.z).abs())
+            .try_into()
+            .unwrap()
+    }
+
+    /// Dot product, the length of the projection on a unit vector `other`.
+    pub fn dot(&self, other: &Coord3) -> isize {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl From<(isize, isize, isize)> for Coord3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Add for Coord3 {
+    type Output = Coord3;
+
+    fn add(self, other: Coord3) -> Coord3 {
+        Coord3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Coord3 {
+    type Output = Coord3;
+
+    fn sub(self, other: Coord3) -> Coord3 {
+        self + -other
+    }
+}
+
+impl Neg for Coord3 {
+    type Output = Coord3;
+
+    fn neg(self) -> Coord3 {
+        Coord3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<isize> for Coord3 {
+    type Output = Coord3;
+
+    fn mul(self, k: isize) -> Coord3 {
+        Coord3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_moves() {
+        let mut coord = Coord::new(500, 0);
+        coord.move_down();
+        coord.move_left();
+        assert_eq!(coord, Coord::new(499, 1));
+        coord.move_right();
+        coord.move_right();
+        assert_eq!(coord, (501, 1).into());
+    }
+
+    #[test]
+    fn test_mdist() {
+        let cell1 = Coord::new(0, 0);
+        let cell2 = Coord::new(10, 1);
+
+        assert_eq!(cell1.mdist(&cell2), 11);
+
+        let cell1 = Coord::new(-5, -5);
+        let cell2 = Coord::new(5, 10);
+
+        assert_eq!(cell1.mdist(&cell2), 25);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Coord::new(1, 1);
+        let b = Coord::new(4, -3);
+        assert_eq!(a.mdist(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.euclidean(&b), 5.0);
+        assert_eq!(b.chebyshev(&a), 4);
+    }
+
+    #[test]
+    fn test_vectors() {
+        let a = Coord::new(1, 1);
+        let b = Coord::new(4, -3);
+        assert_eq!(b - a, Vector2::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(b - (b - a), a);
+        assert_eq!((b - a) * 2, (6, -8).into());
+        assert_eq!((b - a).signum(), Vector2::new(1, -1));
+        assert_eq!(-(b - a) + Vector2::new(3, -4), Vector2::default());
+
+        let mut c = a;
+        c += Direction::Up.vector();
+        assert_eq!(c, Coord::new(1, 0));
+        c -= Direction::Left.vector() * 3;
+        assert_eq!(c, Coord::new(4, 0));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.reverse(), Direction::Up);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+        }
+    }
+
+    #[test]
+    fn test_coord3() {
+        let coord = Coord3::new(1, 2, 3);
+        assert_eq!(coord, (1, 2, 3).into());
+        assert_eq!(coord.mdist(&Coord3::new(-1, 2, 5)), 4);
+
+        let neighbours = coord.neighbours();
+        assert!(neighbours.contains(&Coord3::new(1, 2, 2)));
+        assert!(neighbours.contains(&Coord3::new(0, 2, 3)));
+        assert!(neighbours.iter().all(|n| n.mdist(&coord) == 1));
+    }
+
+    #[test]
+    fn test_coord3_ops() {
+        let a = Coord3::new(1, 2, 3);
+        let b = Coord3::new(-1, 0, 2);
+        assert_eq!(a + b, Coord3::new(0, 2, 5));
+        assert_eq!(a - b, Coord3::new(2, 2, 1));
+        assert_eq!(-a, Coord3::new(-1, -2, -3));
+        assert_eq!(a * 2, Coord3::new(2, 4, 6));
+        assert_eq!(a.dot(&b), 5);
+    }
+}
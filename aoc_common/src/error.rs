@@ -0,0 +1,205 @@
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+/// Error on a puzzle input, located by its 1 based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error on `token`, found in `line`, the 0 based `index` line of the input.
+    pub fn new(index: usize, line: &str, token: &str, expected: &str) -> Self {
+        Self {
+            line: index + 1,
+            column: column(line, token),
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Error on a line ending before `expected`.
+    pub fn end_of_line(index: usize, line: &str, expected: &str) -> Self {
+        Self {
+            line: index + 1,
+            column: line.chars().count() + 1,
+            token: String::new(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// Error at a byte `offset` of the whole `input`, the token being the
+    /// word found there.
+    pub fn at_offset(input: &str, offset: usize, expected: &str) -> Self {
+        let before = &input[..offset];
+        let index = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = input[line_start..].lines().next().unwrap_or("");
+        let token = input[offset..]
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("");
+        if token.is_empty() {
+            return Self {
+                line: index + 1,
+                column: input[line_start..offset].chars().count() + 1,
+                token: String::new(),
+                expected: expected.to_string(),
+            };
+        }
+        Self::new(index, line, token, expected)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        if self.token.is_empty() {
+            write!(f, "unexpected end of line, expected {}", self.expected)
+        } else {
+            write!(f, "unexpected `{}`, expected {}", self.token, self.expected)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error of a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input does not parse.
+    Parse(ParseError),
+    /// The puzzle has no such part, like the second one of the last day.
+    NoSuchPart(u8),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(error) => error.fmt(f),
+            Self::NoSuchPart(part) => write!(f, "the puzzle has no part {}", part),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// 1 based column of `token` within `line`.
+fn column(line: &str, token: &str) -> usize {
+    // Tokens are usually slices of the line, so the pointers give the offset
+    let start = line.as_ptr() as usize;
+    let pos = token.as_ptr() as usize;
+    let offset = if pos >= start && pos + token.len() <= start + line.len() {
+        Some(pos - start)
+    } else {
+        line.find(token)
+    };
+    match offset {
+        Some(offset) => line[..offset].chars().count() + 1,
+        None => 1,
+    }
+}
+
+/// Parse `token` of `line`, the 0 based `index` line of the input.
+pub fn parse_token<T: FromStr>(
+    index: usize,
+    line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    let token = token.trim();
+    token
+        .parse()
+        .map_err(|_| ParseError::new(index, line, token, expected))
+}
+
+/// Unwrap `result`, or print its error and exit.
+pub fn unwrap_or_exit<T, E: fmt::Display>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_new() {
+        let line = "move 1 from x to 1";
+        let token = line.split(' ').nth(3).unwrap();
+        let error = ParseError::new(4, line, token, "a stack number");
+        assert_eq!(error.line, 5);
+        assert_eq!(error.column, 13);
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 13: unexpected `x`, expected a stack number"
+        );
+    }
+
+    #[test]
+    fn test_end_of_line() {
+        let error = ParseError::end_of_line(0, "addx", "a value");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: unexpected end of line, expected a value"
+        );
+    }
+
+    #[test]
+    fn test_at_offset() {
+        let input = "noop\naddx 3\naddx y\n";
+        let error = ParseError::at_offset(input, 17, "a value");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 6: unexpected `y`, expected a value"
+        );
+        let error = ParseError::at_offset(input, input.len(), "an instruction");
+        assert_eq!(error.line, 4);
+        assert_eq!(error.column, 1);
+    }
+
+    #[test]
+    fn test_parse_token() {
+        assert_eq!(parse_token::<i32>(0, "addx -5", "-5", "a value"), Ok(-5));
+        assert_eq!(
+            parse_token::<u32>(2, "R x", "x", "a number of steps"),
+            Err(ParseError {
+                line: 3,
+                column: 3,
+                token: "x".to_string(),
+                expected: "a number of steps".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_solve_error() {
+        let error = ParseError::end_of_line(0, "addx", "a value");
+        assert_eq!(
+            SolveError::from(error.clone()).to_string(),
+            error.to_string()
+        );
+        assert_eq!(
+            SolveError::NoSuchPart(2).to_string(),
+            "the puzzle has no part 2"
+        );
+    }
+}
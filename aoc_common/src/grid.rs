@@ -0,0 +1,291 @@
+use core::fmt;
+use core::fmt::Debug;
+
+use crate::{Coord, Direction, ParseError, Vector2};
+
+/// A fixed size grid of cells stored row after row.
+pub struct Grid<T> {
+    pub height: usize,
+    pub width: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// An empty grid, to be filled with `push` row after row.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            height,
+            width,
+            data: Vec::new(),
+        }
+    }
+
+    /// Grid of the characters of `lines`, turned into cells by `cell`. A
+    /// character without a cell is an error expecting `expected`, as well as
+    /// lines not as long as the first one.
+    pub fn parse(
+        lines: &[String],
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        let mut grid = Self::new(width, lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            for (x, (position, c)) in line.char_indices().enumerate() {
+                let token = &line[position..position + c.len_utf8()];
+                if x == width {
+                    return Err(ParseError::new(index, line, token, "the end of the line"));
+                }
+                match cell(c) {
+                    Some(cell) => grid.push(cell),
+                    None => return Err(ParseError::new(index, line, token, expected)),
+                }
+            }
+            if line.chars().count() < width {
+                return Err(ParseError::end_of_line(index, line, expected));
+            }
+        }
+        Ok(grid)
+    }
+
+    pub fn push(&mut self, cell: T) {
+        self.data.push(cell);
+    }
+
+    pub fn bound(&self, coord: &Coord) -> bool {
+        coord.x < self.width as isize
+            && coord.y < self.height as isize
+            && coord.x >= 0
+            && coord.y >= 0
+    }
+
+    pub fn get_cell(&self, coord: Coord) -> Option<&T> {
+        if !self.bound(&coord) {
+            return None;
+        }
+        Some(&self.data[coord.y as usize * self.width + coord.x as usize])
+    }
+
+    pub fn get_mut_cell(&mut self, coord: Coord) -> Option<&mut T> {
+        if !self.bound(&coord) {
+            return None;
+        }
+        Some(&mut self.data[coord.y as usize * self.width + coord.x as usize])
+    }
+
+    /// Coordinates of every cell, row after row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+    }
+
+    /// Cells of the grid sharing a side with `coord`, clockwise from the top.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |direction| coord + direction.vector())
+            .filter(|neighbour| self.bound(neighbour))
+    }
+
+    /// Cells of the grid touching `coord`, diagonals included.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Vector2::AROUND
+            .iter()
+            .map(move |offset| coord + *offset)
+            .filter(|neighbour| self.bound(neighbour))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.height {
+            return None;
+        }
+        Some(&self.data[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    /// Cells of the column `x`, from top to bottom, none when out of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width { self.height } else { 0 };
+        self.data
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(cells)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Render the grid row after row, each cell as the character given by
+    /// `cell`.
+    pub fn display<F: Fn(&T) -> char>(&self, cell: F) -> Display<'_, T, F> {
+        Display { grid: self, cell }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid where every cell is `cell`.
+    pub fn filled(width: usize, height: usize, cell: T) -> Self {
+        Self {
+            height,
+            width,
+            data: vec![cell; width * height],
+        }
+    }
+}
+
+/// Rendering of a grid, made by `Grid::display`.
+pub struct Display<'a, T, F> {
+    grid: &'a Grid<T>,
+    cell: F,
+}
+
+impl<T, F: Fn(&T) -> char> fmt::Display for Display<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            let line = row.iter().map(&self.cell).collect::<String>();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: fmt::Display> Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}x{} grid:", self.width, self.height)?;
+        for row in self.rows() {
+            let line = row.iter().map(|cell| cell.to_string()).collect::<String>();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_get_cell() {
+        let mut grid = Grid::filled(3, 2, '.');
+        *grid.get_mut_cell(Coord::new(2, 1)).unwrap() = '#';
+
+        assert_eq!(grid.get_cell(Coord::new(2, 1)), Some(&'#'));
+        assert_eq!(grid.get_cell(Coord::new(1, 1)), Some(&'.'));
+        assert_eq!(grid.get_cell(Coord::new(3, 1)), None);
+        assert_eq!(grid.get_cell(Coord::new(0, -1)), None);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut grid = Grid::new(2, 2);
+        for value in 0..4 {
+            grid.push(value);
+        }
+        assert_eq!(grid.get_cell(Coord::new(0, 1)), Some(&2));
+        assert_eq!(grid.get_cell(Coord::new(1, 1)), Some(&3));
+    }
+
+    #[test]
+    fn test_parse() {
+        let lines = ["123".to_string(), "456".to_string()];
+        let grid = Grid::parse(&lines, "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.get_cell(Coord::new(2, 1)), Some(&6));
+
+        let lines = ["123".to_string(), "4x6".to_string()];
+        let error = Grid::parse(&lines, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: unexpected `x`, expected a digit"
+        );
+        let lines = ["123".to_string(), "45".to_string()];
+        let error = Grid::parse(&lines, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: unexpected end of line, expected a digit"
+        );
+        let lines = ["123".to_string(), "4567".to_string()];
+        let error = Grid::parse(&lines, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: unexpected `7`, expected the end of the line"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, '.');
+        let center = grid.neighbours4(Coord::new(1, 1)).collect::<Vec<_>>();
+        assert_eq!(
+            center,
+            vec![
+                Coord::new(1, 0),
+                Coord::new(2, 1),
+                Coord::new(1, 2),
+                Coord::new(0, 1)
+            ]
+        );
+        assert_eq!(grid.neighbours8(Coord::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4(Coord::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Coord::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Coord::new(2, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_rows_columns() {
+        let mut grid = Grid::new(3, 2);
+        for value in 0..6 {
+            grid.push(value);
+        }
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[0, 1, 2], &[3, 4, 5]]
+        );
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&1, &4]);
+        assert_eq!(grid.column(3).count(), 0);
+        let columns = grid
+            .columns()
+            .map(|column| column.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+        assert_eq!(grid.coords().nth(4), Some(Coord::new(1, 1)));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::filled(4, 2, false);
+        *grid.get_mut_cell(Coord::new(1, 0)).unwrap() = true;
+        assert_eq!(
+            grid.display(|cell| if *cell { '#' } else { '.' })
+                .to_string(),
+            ".#..\n....\n"
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let mut grid = Grid::filled(4, 2, '.');
+        *grid.get_mut_cell(Coord::new(1, 0)).unwrap() = '#';
+        assert_eq!(
+            format!("{:?}", grid),
+            indoc!(
+                "
+                4x2 grid:
+                .#..
+                ....
+                "
+            )
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the path of the puzzle input.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Read the puzzle input at runtime.
+///
+/// The input is taken from the first command line argument, then from the
+/// path in `AOC_INPUT`, then from stdin when it is not a terminal. If none of
+/// them is set, `default` is read when it exists, otherwise `example` is used.
+pub fn read_input(default: &Path, example: &str) -> io::Result<String> {
+    read_input_from(env::args_os().nth(1).map(PathBuf::from), default, example)
+}
+
+/// Same as `read_input`, for binaries parsing other arguments than the
+/// input path.
+pub fn read_input_from(arg: Option<PathBuf>, default: &Path, example: &str) -> io::Result<String> {
+    if let Some(path) = arg.or_else(|| env::var_os(INPUT_ENV).map(PathBuf::from)) {
+        return read_path(&path);
+    }
+
+    if !io::stdin().is_terminal() {
+        let input = io::read_to_string(io::stdin())?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    read_default(default, example)
+}
+
+/// Read `default` when it exists, otherwise fall back on `example`.
+pub fn read_default(default: &Path, example: &str) -> io::Result<String> {
+    if default.exists() {
+        return read_path(default);
+    }
+    eprintln!("{} not found, using the example input", default.display());
+    Ok(example.to_string())
+}
+
+/// Read a puzzle input file, `-` meaning stdin.
+pub fn read_path(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        return io::read_to_string(io::stdin());
+    }
+    fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+/// Split a puzzle input into lines, dropping the final line feed.
+pub fn parse_input(input: &str) -> Vec<String> {
+    split_input(input, "\n")
+}
+
+/// Split a puzzle input on `separator`, dropping the final line feed.
+pub fn split_input(input: &str, separator: &str) -> Vec<String> {
+    let input = input.strip_suffix('\n').unwrap_or(input);
+    input
+        .split(separator)
+        .map(|o| o.to_string())
+        .collect::<Vec<String>>()
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_read_default() {
+        let default = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let input = read_default(&default, "example").unwrap();
+        assert!(input.contains("aoc_common"));
+
+        let default = Path::new(env!("CARGO_MANIFEST_DIR")).join("missing.txt");
+        assert_eq!(read_default(&default, "example").unwrap(), "example");
+    }
+
+    #[test]
+    fn test_read_path_missing() {
+        let error = read_path(Path::new("missing.txt")).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with("missing.txt: "));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = indoc!(
+            "
+            1000
+            2000
+
+            3000
+            "
+        );
+        assert_eq!(parse_input(input), vec!["1000", "2000", "", "3000"]);
+    }
+
+    #[test]
+    fn test_parse_input_without_final_line_feed() {
+        assert_eq!(parse_input("A Y\nB X"), vec!["A Y", "B X"]);
+    }
+
+    #[test]
+    fn test_split_input() {
+        let input = indoc!(
+            "
+            [1,1,3,1,1]
+            [1,1,5,1,1]
+
+            [[1],[2,3,4]]
+            [[1],4]
+            "
+        );
+        assert_eq!(
+            split_input(input, "\n\n"),
+            vec!["[1,1,3,1,1]\n[1,1,5,1,1]", "[[1],[2,3,4]]\n[[1],4]"]
+        );
+    }
+}
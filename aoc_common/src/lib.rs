@@ -0,0 +1,17 @@
+//! Pieces shared by every day of the advent of code.
+
+pub mod coord;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod snafu;
+pub mod solution;
+
+pub use coord::{Coord, Coord3, Direction, Vector2};
+pub use error::{parse_token, unwrap_or_exit, ParseError, SolveError};
+pub use grid::Grid;
+pub use input::{parse_input, read_input, read_input_from, split_input};
+pub use logging::init_tracing;
+pub use snafu::Snafu;
+pub use solution::{Answer, Solution};
@@ -0,0 +1,44 @@
+use std::env;
+use std::io;
+
+use tracing_subscriber::EnvFilter;
+
+/// Level of the diagnostics for a number of `-v` flags.
+pub fn level(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    }
+}
+
+/// Send the diagnostics to stderr, filtered by `RUST_LOG` when it is set,
+/// otherwise by the number of `-v` flags.
+pub fn init_tracing(verbose: u8) {
+    let filter = match env::var(EnvFilter::DEFAULT_ENV) {
+        Ok(directives) => EnvFilter::new(directives),
+        Err(_) => EnvFilter::new(level(verbose)),
+    };
+    // Ignore a subscriber already set, by tests for instance
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_target(false)
+        .try_init();
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level(0), "warn");
+        assert_eq!(level(1), "debug");
+        assert_eq!(level(2), "trace");
+        assert_eq!(level(5), "trace");
+    }
+}
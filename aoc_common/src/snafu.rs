@@ -0,0 +1,194 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+/// A SNAFU number: balanced base 5, with the digits `=` (-2), `-` (-1), `0`,
+/// `1` and `2`.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Snafu(pub i64);
+
+impl Snafu {
+    /// Parse `token` of `line`, the 0 based `index` line of the input.
+    pub fn parse_token(index: usize, line: &str, token: &str) -> Result<Self, ParseError> {
+        const EXPECTED: &str = "a SNAFU digit `=`, `-`, `0`, `1` or `2`";
+        if token.is_empty() {
+            return Err(ParseError::end_of_line(index, line, EXPECTED));
+        }
+        // Wide enough for the last digit to bring the number back in range
+        let mut n: i128 = 0;
+        for (position, c) in token.char_indices() {
+            let digit = match c {
+                '=' => -2,
+                '-' => -1,
+                '0' => 0,
+                '1' => 1,
+                '2' => 2,
+                _ => {
+                    let token = &token[position..position + c.len_utf8()];
+                    return Err(ParseError::new(index, line, token, EXPECTED));
+                }
+            };
+            n = n.saturating_mul(5).saturating_add(digit);
+        }
+        let n = n
+            .try_into()
+            .map_err(|_| ParseError::new(index, line, token, "a 64 bits SNAFU number"))?;
+        Ok(Self(n))
+    }
+
+    /// Sum of two numbers, `None` out of the 64 bits range.
+    pub fn checked_add(self, other: Snafu) -> Option<Snafu> {
+        self.0.checked_add(other.0).map(Snafu)
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_token(0, s, s)
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Wide enough to carry past the bounds of i64
+        let mut n = self.0 as i128;
+        if n == 0 {
+            return write!(f, "0");
+        }
+        let mut digits = Vec::new();
+        while n != 0 {
+            let (digit, value) = match n.rem_euclid(5) {
+                0 => ('0', 0),
+                1 => ('1', 1),
+                2 => ('2', 2),
+                3 => ('=', -2),
+                _ => ('-', -1),
+            };
+            digits.push(digit);
+            n = (n - value) / 5;
+        }
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(n: i64) -> Self {
+        Self(n)
+    }
+}
+
+impl From<Snafu> for i64 {
+    fn from(snafu: Snafu) -> Self {
+        snafu.0
+    }
+}
+
+/// Panics when the sum is out of the 64 bits range, see `checked_add`.
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, other: Snafu) -> Snafu {
+        self.checked_add(other)
+            .expect("SNAFU sum out of the 64 bits range")
+    }
+}
+
+impl Sum for Snafu {
+    fn sum<I: Iterator<Item = Snafu>>(iter: I) -> Self {
+        iter.fold(Snafu::default(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_display() {
+        let numbers = [
+            (1, "1"),
+            (2, "2"),
+            (3, "1="),
+            (4, "1-"),
+            (5, "10"),
+            (8, "2="),
+            (9, "2-"),
+            (10, "20"),
+            (15, "1=0"),
+            (20, "1-0"),
+            (2022, "1=11-2"),
+            (12345, "1-0---0"),
+            (314159265, "1121-1110-1=0"),
+            (0, "0"),
+            (-3, "-2"),
+        ];
+        for (n, snafu) in numbers {
+            assert_eq!(Snafu(n).to_string(), snafu);
+            assert_eq!(snafu.parse::<Snafu>().unwrap(), Snafu(n));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = (-5000..5000).chain([i64::MIN, i64::MIN + 1, i64::MAX - 1, i64::MAX]);
+        for n in values {
+            let snafu = Snafu::from(n);
+            assert_eq!(i64::from(snafu.to_string().parse::<Snafu>().unwrap()), n);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            "1=3-".parse::<Snafu>().unwrap_err().to_string(),
+            "line 1, column 3: unexpected `3`, expected a SNAFU digit `=`, `-`, `0`, `1` or `2`"
+        );
+        assert_eq!(
+            "".parse::<Snafu>().unwrap_err().to_string(),
+            "line 1, column 1: unexpected end of line, expected a SNAFU digit `=`, `-`, `0`, `1` or `2`"
+        );
+        let snafu = format!("1{}", Snafu(i64::MAX));
+        assert_eq!(
+            snafu.parse::<Snafu>().unwrap_err().expected,
+            "a 64 bits SNAFU number"
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let numbers = [Snafu(1), Snafu(2), Snafu(-10)];
+        assert_eq!(numbers.iter().sum::<Snafu>(), Snafu(-7));
+        assert_eq!(numbers.into_iter().sum::<Snafu>(), Snafu(-7));
+        assert_eq!(Snafu(3) + Snafu(4), Snafu(7));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        assert_eq!(
+            Snafu(i64::MAX - 1).checked_add(Snafu(1)),
+            Some(Snafu(i64::MAX))
+        );
+        assert_eq!(Snafu(i64::MAX).checked_add(Snafu(1)), None);
+        assert_eq!(Snafu(i64::MIN).checked_add(Snafu(-1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "SNAFU sum out of the 64 bits range")]
+    fn test_add_overflow() {
+        let _ = Snafu(i64::MAX) + Snafu(1);
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use crate::SolveError;
+
+/// Answer of a puzzle part, a number for most days.
+pub type Answer = Box<dyn Display>;
+
+/// Both parts of the puzzle of a day.
+pub trait Solution {
+    /// Title of the puzzle.
+    fn name(&self) -> &'static str;
+
+    fn day(&self) -> u8;
+
+    /// Example input given with the puzzle.
+    fn example(&self) -> &'static str;
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError>;
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError>;
+
+    /// Parts solved so far.
+    fn parts(&self) -> &'static [u8] {
+        &[1, 2]
+    }
+
+    /// Run one part, `None` if it is not solved.
+    fn solve(&self, part: u8, input: &str) -> Option<Result<Answer, SolveError>> {
+        if !self.parts().contains(&part) {
+            return None;
+        }
+        match part {
+            1 => Some(self.part1(input)),
+            2 => Some(self.part2(input)),
+            _ => None,
+        }
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        fn name(&self) -> &'static str {
+            "Sum"
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn example(&self) -> &'static str {
+            "1\n2\n"
+        }
+
+        fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+            let sum = input
+                .lines()
+                .enumerate()
+                .map(|(index, line)| crate::parse_token::<u32>(index, line, line, "a number"))
+                .sum::<Result<u32, crate::ParseError>>()?;
+            Ok(Box::new(sum))
+        }
+
+        fn part2(&self, _input: &str) -> Result<Answer, SolveError> {
+            Err(SolveError::NoSuchPart(2))
+        }
+
+        fn parts(&self) -> &'static [u8] {
+            &[1]
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let answer = Sum.solve(1, Sum.example()).unwrap().unwrap();
+        assert_eq!(answer.to_string(), "3");
+        assert!(Sum.solve(1, "1\nx\n").unwrap().is_err());
+        assert!(Sum.solve(2, Sum.example()).is_none());
+        assert!(Sum.solve(3, Sum.example()).is_none());
+    }
+}
@@ -0,0 +1,131 @@
+//! Day 1: Calorie Counting.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::instrument;
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    1000
+    2000
+    3000
+
+    4000
+
+    5000
+    6000
+
+    7000
+    8000
+    9000
+
+    10000
+    "
+);
+
+pub const NB_VALUES: usize = 3;
+
+/// Calories carried by each elf.
+fn calories(mut input: Vec<String>) -> Result<Vec<i32>, ParseError> {
+    let mut partial_sum = 0;
+    let mut output = Vec::new();
+
+    // Push an empty line at the end to mark and of file and simplify algorithm
+    input.push("".to_string());
+
+    for (index, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            output.push(partial_sum);
+            partial_sum = 0;
+        } else {
+            partial_sum += parse_token::<i32>(index, line, line, "a number of calories")?;
+        }
+    }
+    Ok(output)
+}
+
+#[instrument(skip_all, fields(day = 1, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<i32, ParseError> {
+    let output = calories(input)?;
+    Ok(*output.iter().max().unwrap())
+}
+
+#[instrument(skip_all, fields(day = 1, part = 2))]
+pub fn part2(input: Vec<String>, nb_values: usize) -> Result<i32, ParseError> {
+    let mut output = calories(input)?;
+    output.sort_by(|a, b| b.cmp(a));
+    Ok(output.iter().take(nb_values).sum())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn name(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input), NB_VALUES)?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 24000);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input, 3).unwrap();
+        assert_eq!(answer, 45000);
+    }
+
+    #[test]
+    fn test_run_invalid_calories() {
+        let input = parse_input("1000\n2000\n\n3k\n");
+        assert_eq!(
+            part1(input.clone()).unwrap_err().to_string(),
+            "line 4, column 1: unexpected `3k`, expected a number of calories"
+        );
+        assert_eq!(
+            part2(input, 3).unwrap_err().to_string(),
+            "line 4, column 1: unexpected `3k`, expected a number of calories"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day01.example();
+        assert_eq!(Day01.part1(example).unwrap().to_string(), "24000");
+        assert_eq!(Day01.part2(example).unwrap().to_string(), "45000");
+    }
+}
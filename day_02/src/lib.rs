@@ -0,0 +1,231 @@
+//! Day 2: Rock Paper Scissors.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::slice::Iter;
+use tracing::{instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    A Y
+    B X
+    C Z
+    "
+);
+
+#[derive(Debug, PartialEq)]
+enum GameStatus {
+    Lost,
+    Drawn,
+    Win,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Choice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Choice {
+    pub fn iter() -> Iter<'static, Choice> {
+        static CHOICE: [Choice; 3] = [Choice::Rock, Choice::Paper, Choice::Scissors];
+        CHOICE.iter()
+    }
+}
+
+fn parse_opponent_choice(index: usize, line: &str, choice: &str) -> Result<Choice, ParseError> {
+    match choice {
+        "A" => Ok(Choice::Rock),
+        "B" => Ok(Choice::Paper),
+        "C" => Ok(Choice::Scissors),
+        _ => Err(ParseError::new(index, line, choice, "`A`, `B` or `C`")),
+    }
+}
+
+fn parse_player_choice(index: usize, line: &str, choice: &str) -> Result<Choice, ParseError> {
+    match choice {
+        "X" => Ok(Choice::Rock),
+        "Y" => Ok(Choice::Paper),
+        "Z" => Ok(Choice::Scissors),
+        _ => Err(ParseError::new(index, line, choice, "`X`, `Y` or `Z`")),
+    }
+}
+
+fn parse_game_result(index: usize, line: &str, choice: &str) -> Result<GameStatus, ParseError> {
+    match choice {
+        "X" => Ok(GameStatus::Lost),
+        "Y" => Ok(GameStatus::Drawn),
+        "Z" => Ok(GameStatus::Win),
+        _ => Err(ParseError::new(index, line, choice, "`X`, `Y` or `Z`")),
+    }
+}
+
+/// Parse a line, the second column being read by `parse_second`.
+fn parse_line<T>(
+    index: usize,
+    line: &str,
+    parse_second: fn(usize, &str, &str) -> Result<T, ParseError>,
+) -> Result<(T, Choice), ParseError> {
+    let choice: Vec<&str> = line.split(' ').collect();
+    let second = choice
+        .get(1)
+        .ok_or_else(|| ParseError::end_of_line(index, line, "the second column"))?;
+    Ok((
+        parse_second(index, line, second)?,
+        parse_opponent_choice(index, line, choice[0])?,
+    ))
+}
+
+fn game_result(player_choice: Choice, opponent_choice: Choice) -> GameStatus {
+    match (player_choice, opponent_choice) {
+        (Choice::Rock, Choice::Rock) => GameStatus::Drawn,
+        (Choice::Rock, Choice::Paper) => GameStatus::Lost,
+        (Choice::Rock, Choice::Scissors) => GameStatus::Win,
+        (Choice::Paper, Choice::Rock) => GameStatus::Win,
+        (Choice::Paper, Choice::Paper) => GameStatus::Drawn,
+        (Choice::Paper, Choice::Scissors) => GameStatus::Lost,
+        (Choice::Scissors, Choice::Rock) => GameStatus::Lost,
+        (Choice::Scissors, Choice::Paper) => GameStatus::Win,
+        (Choice::Scissors, Choice::Scissors) => GameStatus::Drawn,
+    }
+}
+
+fn what_to_play(expected_game_result: GameStatus, opponent_choice: Choice) -> Option<Choice> {
+    for choice in Choice::iter() {
+        if game_result(*choice, opponent_choice) == expected_game_result {
+            return Some(*choice);
+        }
+    }
+    None
+}
+
+struct Player {
+    score: u32,
+}
+
+impl Player {
+    fn new() -> Self {
+        Self { score: 0 }
+    }
+
+    fn calculate_score(&mut self, player_choice: Choice, opponent_choice: Choice) {
+        let choice_score = match player_choice {
+            Choice::Rock => 1,
+            Choice::Paper => 2,
+            Choice::Scissors => 3,
+        };
+        self.score += choice_score;
+
+        let game_score = match game_result(player_choice, opponent_choice) {
+            GameStatus::Win => 6,
+            GameStatus::Drawn => 3,
+            GameStatus::Lost => 0,
+        };
+        self.score += game_score;
+    }
+}
+
+#[instrument(skip_all, fields(day = 2, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<u32, ParseError> {
+    let mut player = Player::new();
+
+    for (index, line) in input.iter().enumerate() {
+        let (player_choice, opponent_choice) = parse_line(index, line, parse_player_choice)?;
+        trace!(?player_choice, ?opponent_choice);
+        player.calculate_score(player_choice, opponent_choice);
+        trace!(score = player.score);
+    }
+    Ok(player.score)
+}
+
+#[instrument(skip_all, fields(day = 2, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<u32, ParseError> {
+    let mut player = Player::new();
+
+    for (index, line) in input.iter().enumerate() {
+        let (player_game_result, opponent_choice) = parse_line(index, line, parse_game_result)?;
+        trace!(?player_game_result, ?opponent_choice);
+        let player_choice = what_to_play(player_game_result, opponent_choice).unwrap();
+        trace!(?player_choice);
+        player.calculate_score(player_choice, opponent_choice);
+        trace!(score = player.score);
+    }
+    Ok(player.score)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn name(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 12);
+    }
+
+    #[test]
+    fn test_run_invalid_choice() {
+        for run in [part1, part2] {
+            let input = parse_input("A Y\nB W\n");
+            assert_eq!(
+                run(input).unwrap_err().to_string(),
+                "line 2, column 3: unexpected `W`, expected `X`, `Y` or `Z`"
+            );
+            let input = parse_input("A\n");
+            assert_eq!(
+                run(input).unwrap_err().to_string(),
+                "line 1, column 2: unexpected end of line, expected the second column"
+            );
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day02.example();
+        assert_eq!(Day02.part1(example).unwrap().to_string(), "15");
+        assert_eq!(Day02.part2(example).unwrap().to_string(), "12");
+    }
+}
@@ -0,0 +1,165 @@
+//! Day 3: Rucksack Reorganization.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::collections::HashMap;
+use tracing::{instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    vJrwpWtwJgWrhcsFMMfFFhFp
+    jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+    PmmdzqPrVvPwwTWBwg
+    wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+    ttgJtRGJQctTZtZT
+    CrZsJsPPZsGzwwsLwLmpwMDw
+    "
+);
+
+fn build_index() -> HashMap<String, usize> {
+    let mut index: HashMap<String, usize> = HashMap::new();
+    for (id, item) in ('a'..='z').enumerate() {
+        index.insert(item.to_string(), id + 1);
+    }
+
+    for (id, item) in ('A'..='Z').enumerate() {
+        index.insert(item.to_string(), id + 27);
+    }
+
+    index
+}
+
+/// First item of `first` found in every one of `others`.
+fn find_common_char(first: &str, others: &[&str]) -> Option<String> {
+    for character in first.chars() {
+        if others.iter().all(|other| other.contains(character)) {
+            return Some(character.to_string());
+        }
+    }
+    None
+}
+
+#[instrument(skip_all, fields(day = 3, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<u32, ParseError> {
+    let mut output = 0;
+    let index = build_index();
+    for (line_index, line) in input.iter().enumerate() {
+        let (first_part, last_part) = line.split_at(line.len() / 2);
+        let common_char = find_common_char(first_part, &[last_part]).ok_or_else(|| {
+            ParseError::new(line_index, line, line, "an item type in both compartments")
+        })?;
+        output += index.get(&common_char).ok_or_else(|| {
+            ParseError::new(
+                line_index,
+                line,
+                &common_char,
+                "an item type from `a` to `Z`",
+            )
+        })?;
+    }
+    Ok(output.try_into().unwrap())
+}
+
+#[instrument(skip_all, fields(day = 3, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<u32, ParseError> {
+    let mut output = 0;
+    let mut elements_read = 0;
+    let mut elements: [String; 3] = ["".to_string(), "".to_string(), "".to_string()];
+    let index = build_index();
+    for (line_index, line) in input.iter().enumerate() {
+        elements[elements_read] = line.to_string();
+        elements_read += 1;
+        if elements_read == 3 {
+            trace!(?elements);
+            // A missing badge is reported on the last line of the group
+            let character = find_common_char(&elements[0], &[&elements[1], &elements[2]])
+                .ok_or_else(|| {
+                    ParseError::new(line_index, line, line, "an item type common to the group")
+                })?;
+            trace!(?character);
+            output += index.get(&character).ok_or_else(|| {
+                ParseError::new(line_index, line, &character, "an item type from `a` to `Z`")
+            })?;
+            elements_read = 0;
+        }
+    }
+    Ok(output.try_into().unwrap())
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn name(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 157);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 70);
+    }
+
+    #[test]
+    fn test_run_invalid_rucksack() {
+        let input = parse_input("vJrwpWtwJgWrhcsFMMfFFhFp\nabcd\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 1: unexpected `abcd`, expected an item type in both compartments"
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_group() {
+        let input = parse_input("abc\ndef\nghi\n");
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 3, column 1: unexpected `ghi`, expected an item type common to the group"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day03.example();
+        assert_eq!(Day03.part1(example).unwrap().to_string(), "157");
+        assert_eq!(Day03.part2(example).unwrap().to_string(), "70");
+    }
+}
@@ -0,0 +1,16 @@
+use std::path::Path;
+
+use day_03::{parse_input, part1, part2, EXAMPLE};
+
+fn main() {
+    aoc_common::init_tracing(0);
+    let input_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+    let input = aoc_common::read_input(input_path, EXAMPLE).expect("Cannot read puzzle input");
+    let input = parse_input(&input);
+
+    let answer = aoc_common::unwrap_or_exit(part1(input.clone()));
+    println!("Part 1: {}", answer);
+
+    let answer = aoc_common::unwrap_or_exit(part2(input));
+    println!("Part 2: {}", answer);
+}
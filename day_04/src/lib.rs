@@ -0,0 +1,277 @@
+//! Day 4: Camp Cleanup.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::{instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    2-4,6-8
+    2-3,4-5
+    5-7,7-9
+    2-8,3-7
+    6-6,4-6
+    2-6,4-8
+    "
+);
+
+#[derive(PartialEq, Debug)]
+struct Elf {
+    clean_section_start: u32,
+    clean_section_end: u32,
+}
+
+impl Elf {
+    fn new(clean_section_start: u32, clean_section_end: u32) -> Self {
+        Self {
+            clean_section_start,
+            clean_section_end,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+struct Elfpair {
+    clean_section_start: u32,
+    clean_section_end: u32,
+}
+
+impl Elfpair {
+    fn new(clean_section_start: u32, clean_section_end: u32) -> Self {
+        Self {
+            clean_section_start,
+            clean_section_end,
+        }
+    }
+}
+
+fn is_section_contained(elf: Elf, elfpair: Elfpair) -> bool {
+    if elfpair.clean_section_start >= elf.clean_section_start
+        && elfpair.clean_section_end <= elf.clean_section_end
+    {
+        return true;
+    }
+
+    if elf.clean_section_start >= elfpair.clean_section_start
+        && elf.clean_section_end <= elfpair.clean_section_end
+    {
+        return true;
+    }
+    false
+}
+
+fn is_section_overlapping(elf: Elf, elfpair: Elfpair) -> bool {
+    if elf.clean_section_end < elfpair.clean_section_start
+        || elfpair.clean_section_end < elf.clean_section_start
+    {
+        return false;
+    }
+
+    true
+}
+
+fn parse_sections(index: usize, line: &str, sections: &str) -> Result<(u32, u32), ParseError> {
+    let (start, end) = sections
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(index, line, sections, "a `start-end` range"))?;
+    Ok((
+        parse_token(index, line, start, "a section id")?,
+        parse_token(index, line, end, "a section id")?,
+    ))
+}
+
+fn parse_line(index: usize, line: &str) -> Result<(Elf, Elfpair), ParseError> {
+    let elves: Vec<&str> = line.split(',').collect();
+    let clean_sections = parse_sections(index, line, elves[0])?;
+    let elf = Elf::new(clean_sections.0, clean_sections.1);
+
+    let elfpair = elves
+        .get(1)
+        .ok_or_else(|| ParseError::end_of_line(index, line, "`,` and a second range"))?;
+    let clean_sections = parse_sections(index, line, elfpair)?;
+    let elfpair = Elfpair::new(clean_sections.0, clean_sections.1);
+
+    Ok((elf, elfpair))
+}
+
+/// Number of pairs for which `check` holds.
+fn count_pairs(input: Vec<String>, check: fn(Elf, Elfpair) -> bool) -> Result<u32, ParseError> {
+    let mut output: u32 = 0;
+    for (index, line) in input.iter().enumerate() {
+        trace!(?line);
+        let (elf, elfpair) = parse_line(index, line)?;
+        if check(elf, elfpair) {
+            trace!("matching");
+            output += 1;
+        }
+    }
+    Ok(output)
+}
+
+#[instrument(skip_all, fields(day = 4, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<u32, ParseError> {
+    count_pairs(input, is_section_contained)
+}
+
+#[instrument(skip_all, fields(day = 4, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<u32, ParseError> {
+    count_pairs(input, is_section_overlapping)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn name(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_is_section_contained_01() {
+        let elf = Elf::new(1, 3);
+        let elfpair = Elfpair::new(1, 2);
+        assert_eq!(is_section_contained(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_contained_02() {
+        let elf = Elf::new(1, 3);
+        let elfpair = Elfpair::new(1, 3);
+        assert_eq!(is_section_contained(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_contained_03() {
+        let elf = Elf::new(1, 3);
+        let elfpair = Elfpair::new(1, 4);
+        assert_eq!(is_section_contained(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_contained_04() {
+        let elf = Elf::new(2, 4);
+        let elfpair = Elfpair::new(1, 4);
+        assert_eq!(is_section_contained(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_contained_05() {
+        let elf = Elf::new(2, 4);
+        let elfpair = Elfpair::new(3, 4);
+        assert_eq!(is_section_contained(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_01() {
+        let elf = Elf::new(2, 4);
+        let elfpair = Elfpair::new(6, 8);
+        assert_eq!(is_section_overlapping(elf, elfpair), false);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_02() {
+        let elf = Elf::new(2, 3);
+        let elfpair = Elfpair::new(4, 5);
+        assert_eq!(is_section_overlapping(elf, elfpair), false);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_03() {
+        let elf = Elf::new(5, 7);
+        let elfpair = Elfpair::new(7, 9);
+        assert_eq!(is_section_overlapping(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_04() {
+        let elf = Elf::new(2, 8);
+        let elfpair = Elfpair::new(3, 7);
+        assert_eq!(is_section_overlapping(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_05() {
+        let elf = Elf::new(6, 6);
+        let elfpair = Elfpair::new(4, 6);
+        assert_eq!(is_section_overlapping(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_is_section_overlapping_06() {
+        let elf = Elf::new(2, 6);
+        let elfpair = Elfpair::new(4, 8);
+        assert_eq!(is_section_overlapping(elf, elfpair), true);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let elf = Elf::new(1, 2);
+        let elfpair = Elfpair::new(2, 3);
+        assert_eq!(parse_line(0, "1-2,2-3"), Ok((elf, elfpair)));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 4);
+    }
+
+    #[test]
+    fn test_run_invalid_range() {
+        let input = parse_input("2-4,6-8\n2-3,4+5\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 5: unexpected `4+5`, expected a `start-end` range"
+        );
+        let input = parse_input("2-4\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 4: unexpected end of line, expected `,` and a second range"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day04.example();
+        assert_eq!(Day04.part1(example).unwrap().to_string(), "2");
+        assert_eq!(Day04.part2(example).unwrap().to_string(), "4");
+    }
+}
@@ -0,0 +1,491 @@
+//! Day 5: Supply Stacks.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+        [D]
+    [N] [C]
+    [Z] [M] [P]
+     1   2   3
+
+    move 1 from 2 to 1
+    move 3 from 1 to 3
+    move 2 from 2 to 1
+    move 1 from 1 to 2
+    "
+);
+
+type Map = Vec<Vec<String>>;
+type Actions = Vec<Action>;
+
+fn parse(input: Vec<String>) -> Result<(Map, Actions), ParseError> {
+    // Split vec in 2 parts (map, actions)
+    let mut delim_line = 0;
+    for (index, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            delim_line = index;
+        }
+    }
+
+    if delim_line == 0 {
+        return Err(ParseError::end_of_line(
+            input.len(),
+            "",
+            "an empty line between the stacks and the moves",
+        ));
+    }
+
+    let (map, actions) = input.split_at(delim_line);
+    debug!(?map);
+    debug!(?actions);
+    let map = parse_map(map)?;
+    let actions = parse_actions(actions).map_err(|e| ParseError {
+        line: e.line + delim_line,
+        ..e
+    })?;
+    Ok((map, actions))
+}
+
+fn parse_map(input: &[String]) -> Result<Vec<Vec<String>>, ParseError> {
+    // Get number of element
+    let mut input = input.to_vec();
+    let numbers = input.pop().unwrap_or_default();
+    let last = numbers.split("   ").last().unwrap_or_default();
+    let nb_elem = parse_token::<usize>(input.len(), &numbers, last, "the number of stacks")?;
+    debug!(nb_elem);
+
+    let mut map: Vec<Vec<String>> = Vec::new();
+    for _ in 0..nb_elem {
+        map.push(Vec::new());
+    }
+
+    for (index, line) in input.iter().enumerate() {
+        #[allow(unused_assignments)]
+        let (mut case, mut remaining) = line.split_at(0);
+        for item in map.iter_mut().take(nb_elem) {
+            (case, remaining) = remaining.split_at(3);
+            if !remaining.is_empty() {
+                remaining = remaining.strip_prefix(' ').ok_or_else(|| {
+                    ParseError::new(index, line, remaining, "a space between stacks")
+                })?;
+            } else {
+                remaining = "   ";
+            }
+            // dbg!(&case);
+            // dbg!(&remaining);
+            if case != "   " {
+                item.push(case.replace(['[', ']'], "").to_string());
+            }
+        }
+    }
+    for item in map.iter_mut() {
+        item.reverse();
+    }
+    Ok(map)
+}
+
+fn get_map(map: &[Vec<String>]) -> String {
+    let mut line = Vec::new();
+    let height = map.iter().map(|x| x.len()).max().unwrap();
+    for case in 0..height {
+        let mut column = Vec::new();
+        for stack in 0..map.len() {
+            column.push(match map.get(stack).unwrap().get(case) {
+                Some(x) => format!("[{}]", x),
+                None => "   ".to_string(),
+            });
+        }
+        line.push(column)
+    }
+
+    let out = line
+        .iter()
+        .rev()
+        .map(|x| x.join(" ").trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    out
+}
+
+fn top_case(map: Map) -> String {
+    let top_case = map
+        .iter()
+        .map(|x| x.iter().rfind(|x| !x.contains(' ')).unwrap().to_string())
+        .collect::<Vec<String>>()
+        .join("");
+
+    top_case
+}
+
+/// Move `nb` crates from `src` to `dst`, `one_by_one` or all at once.
+fn move_case(map: &mut Map, nb: usize, src: usize, dst: usize, one_by_one: bool) {
+    let src_stack = map.get(src - 1).unwrap();
+    let (lhs, rhs) = src_stack.split_at(src_stack.len() - nb);
+    let lhs = lhs.to_vec();
+    let mut rhs = rhs.to_vec();
+    if one_by_one {
+        rhs.reverse();
+    }
+    map[src - 1] = lhs;
+    map[dst - 1].append(&mut rhs);
+}
+
+#[derive(Debug, PartialEq)]
+struct Action {
+    nb: usize,
+    src: usize,
+    dst: usize,
+}
+
+fn parse_action(index: usize, line: &str) -> Result<Action, ParseError> {
+    let words = line.split_whitespace().collect::<Vec<&str>>();
+    let word = |n: usize, expected: &str| {
+        words
+            .get(n)
+            .copied()
+            .ok_or_else(|| ParseError::end_of_line(index, line, expected))
+    };
+
+    for (n, keyword) in [(0, "`move`"), (2, "`from`"), (4, "`to`")] {
+        let token = word(n, keyword)?;
+        if token != keyword.trim_matches('`') {
+            return Err(ParseError::new(index, line, token, keyword));
+        }
+    }
+
+    let number = |n: usize, expected: &str| parse_token(index, line, word(n, expected)?, expected);
+    Ok(Action {
+        nb: number(1, "a number of crates")?,
+        src: number(3, "a source stack")?,
+        dst: number(5, "a destination stack")?,
+    })
+}
+
+fn parse_actions(input: &[String]) -> Result<Vec<Action>, ParseError> {
+    // The first line is the empty one separating the actions from the map
+    input
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(index, line)| parse_action(index, line))
+        .collect()
+}
+
+fn rearrange(input: Vec<String>, one_by_one: bool) -> Result<String, ParseError> {
+    let (mut map, actions) = parse(input)?;
+    trace!("stacks:\n{}", get_map(&map));
+    for action in actions {
+        move_case(&mut map, action.nb, action.src, action.dst, one_by_one);
+        trace!("stacks:\n{}", get_map(&map));
+    }
+    Ok(top_case(map))
+}
+
+#[instrument(skip_all, fields(day = 5, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<String, ParseError> {
+    rearrange(input, true)
+}
+
+#[instrument(skip_all, fields(day = 5, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<String, ParseError> {
+    rearrange(input, false)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn name(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_map_01() {
+        let input = indoc!(
+            "
+                [D]
+            [N] [C]
+            [Z] [M] [P]
+             1   2   3
+            "
+        );
+
+        let mut input_lines = parse_input(input);
+        let map = parse_map(&input_lines).unwrap();
+        println!("{}", get_map(&map));
+        input_lines.pop();
+        assert_eq!(input_lines.join("\n"), get_map(&map));
+    }
+
+    #[test]
+    fn test_parse_map_02() {
+        let input = indoc!(
+            "
+                [D]
+            [N] [C]
+            [Z] [M] [P]
+             1   2   3   4
+            "
+        );
+
+        let mut input_lines = parse_input(input);
+        let map = parse_map(&input_lines).unwrap();
+        println!("{}", get_map(&map));
+        input_lines.pop();
+        assert_eq!(input_lines.join("\n"), get_map(&map));
+    }
+
+    #[test]
+    fn test_parse_map_03() {
+        let input = indoc!(
+            "
+                        [D]
+                [D]     [C]
+            [N] [C]     [B]
+            [Z] [M] [P] [A]
+             1   2   3   4
+            "
+        );
+
+        let mut input_lines = parse_input(input);
+        let map = parse_map(&input_lines).unwrap();
+        println!("{}", get_map(&map));
+        input_lines.pop();
+        assert_eq!(input_lines.join("\n"), get_map(&map));
+    }
+
+    #[test]
+    fn test_top_case() {
+        let input = indoc!(
+            "
+                        [D]
+                [D]     [C]
+            [N] [C]     [B]
+            [Z] [M] [P] [A]
+             1   2   3   4
+            "
+        );
+
+        let input_lines = parse_input(input);
+        let map = parse_map(&input_lines).unwrap();
+        assert_eq!(top_case(map), "NDPD".to_string());
+    }
+
+    #[test]
+    fn test_move_case_01() {
+        let src = indoc!(
+            "
+                        [H]
+                [D]     [G]
+            [N] [C]     [B]
+            [Z] [M] [P] [A]
+             1   2   3   4
+            "
+        );
+        let dst = indoc!(
+            "
+                        [C]
+                        [D]
+                        [H]
+                        [G]
+            [N]         [B]
+            [Z] [M] [P] [A]"
+        );
+
+        let input_lines = parse_input(src);
+        let mut map = parse_map(&input_lines).unwrap();
+        println!("{}", get_map(&map));
+        move_case(&mut map, 2, 2, 4, true);
+        let dst_map = get_map(&map);
+        println!("{}", get_map(&map));
+        assert_eq!(dst, dst_map)
+    }
+
+    #[test]
+    fn test_move_case_01_at_once() {
+        let src = indoc!(
+            "
+                        [H]
+                [D]     [G]
+            [N] [C]     [B]
+            [Z] [M] [P] [A]
+             1   2   3   4
+            "
+        );
+        let dst = indoc!(
+            "
+                        [D]
+                        [C]
+                        [H]
+                        [G]
+            [N]         [B]
+            [Z] [M] [P] [A]"
+        );
+
+        let input_lines = parse_input(src);
+        let mut map = parse_map(&input_lines).unwrap();
+        move_case(&mut map, 2, 2, 4, false);
+        assert_eq!(dst, get_map(&map))
+    }
+
+    #[test]
+    fn test_move_case_02() {
+        let src = indoc!(
+            "
+                        [H]
+                [D]     [G]
+            [N] [C]     [B]
+            [Z] [M] [P] [A]
+             1   2   3   4
+            "
+        );
+        let dst = indoc!(
+            "
+                        [H]
+                [D]     [G]
+                [C] [N] [B]
+            [Z] [M] [P] [A]"
+        );
+
+        let input_lines = parse_input(src);
+        let mut map = parse_map(&input_lines).unwrap();
+        println!("{}", get_map(&map));
+        move_case(&mut map, 1, 1, 3, true);
+        let dst_map = get_map(&map);
+        println!("{}", get_map(&map));
+        assert_eq!(dst, dst_map)
+    }
+
+    #[test]
+    fn test_parse_actions_01() {
+        let input = indoc!(
+            "
+
+            move 1 from 2 to 1
+            move 3 from 1 to 3
+            move 2 from 2 to 1
+            move 1 from 1 to 2
+            "
+        );
+
+        let input_lines = parse_input(input);
+        let actions = parse_actions(&input_lines).unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                Action {
+                    nb: 1,
+                    src: 2,
+                    dst: 1
+                },
+                Action {
+                    nb: 3,
+                    src: 1,
+                    dst: 3
+                },
+                Action {
+                    nb: 2,
+                    src: 2,
+                    dst: 1
+                },
+                Action {
+                    nb: 1,
+                    src: 1,
+                    dst: 2
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, "CMZ".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, "MCD".to_string());
+    }
+
+    #[test]
+    fn test_parse_big_map() {
+        let input = parse_input(indoc!(
+            "
+                    [H]         [S]         [D]
+                [S] [C]         [C]     [Q] [L]
+                [C] [R] [Z]     [R]     [H] [Z]
+                [G] [N] [H] [S] [B]     [R] [F]
+            [D] [T] [Q] [F] [Q] [Z]     [Z] [N]
+            [Z] [W] [F] [N] [F] [W] [J] [V] [G]
+            [T] [R] [B] [C] [L] [P] [F] [L] [H]
+            [H] [Q] [P] [L] [G] [V] [Z] [D] [B]
+             1   2   3   4   5   6   7   8   9 
+
+            move 2 from 7 to 2
+            "
+        ));
+        let answer = part1(input.clone()).unwrap();
+        assert_eq!(answer, "DFHZSSZQD".to_string());
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, "DJHZSSZQD".to_string());
+    }
+
+    #[test]
+    fn test_run_invalid_action() {
+        let input = parse_input(&EXAMPLE.replace("move 2 from 2", "move 2 from x"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 8, column 13: unexpected `x`, expected a source stack"
+        );
+        let input = parse_input(&EXAMPLE.replace("move 3 from 1 to 3", "move 3 from 1"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 7, column 14: unexpected end of line, expected `to`"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day05.example();
+        assert_eq!(Day05.part1(example).unwrap().to_string(), "CMZ");
+        assert_eq!(Day05.part2(example).unwrap().to_string(), "MCD");
+    }
+}
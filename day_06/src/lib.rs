@@ -0,0 +1,184 @@
+//! Day 6: Tuning Trouble.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::collections::HashMap;
+use tracing::instrument;
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw
+    "
+);
+
+const PACKET_MARKER_SIZE: usize = 4;
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+fn find_first_marker(s: String, marker_size: usize) -> Option<usize> {
+    let v = s.chars().enumerate().collect::<Vec<(usize, char)>>();
+
+    v.windows(marker_size)
+        .filter_map(|tuple_list| {
+            let mut hash: HashMap<char, usize> = HashMap::new();
+            tuple_list.iter().for_each(|tuple| {
+                let character = hash.entry(tuple.1).or_insert(0);
+                *character += 1;
+            });
+            if hash.len() == marker_size {
+                Some(tuple_list.last().unwrap().0 + 1)
+            } else {
+                None
+            }
+        })
+        .min()
+}
+
+#[allow(dead_code)]
+fn find_all_markers(s: String) -> Vec<usize> {
+    let v = s.chars().enumerate().collect::<Vec<(usize, char)>>();
+
+    v.windows(4)
+        .filter_map(|tuple_list| {
+            let mut hash: HashMap<char, usize> = HashMap::new();
+            tuple_list.iter().for_each(|tuple| {
+                let character = hash.entry(tuple.1).or_insert(0);
+                *character += 1;
+            });
+            if hash.len() == 4 {
+                Some(tuple_list.last().unwrap().0 + 1)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[instrument(skip_all, fields(day = 6, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let datastream = input.join("");
+    find_first_marker(datastream.clone(), PACKET_MARKER_SIZE)
+        .ok_or_else(|| ParseError::end_of_line(0, &datastream, "a start-of-packet marker"))
+}
+
+#[instrument(skip_all, fields(day = 6, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let datastream = input.join("");
+    find_first_marker(datastream.clone(), MESSAGE_MARKER_SIZE)
+        .ok_or_else(|| ParseError::end_of_line(0, &datastream, "a start-of-message marker"))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn name(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_find_first_marker_01() {
+        let s = String::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+        assert_eq!(find_first_marker(s.clone(), 4), Some(7));
+        assert_eq!(find_first_marker(s, 14), Some(19));
+    }
+
+    #[test]
+    fn test_find_first_marker_02() {
+        let s = String::from("bvwbjplbgvbhsrlpgdmjqwftvncz");
+        assert_eq!(find_first_marker(s.clone(), 4), Some(5));
+        assert_eq!(find_first_marker(s, 14), Some(23));
+    }
+
+    #[test]
+    fn test_find_first_marker_03() {
+        let s = String::from("nppdvjthqldpwncqszvftbrmjlhg");
+        assert_eq!(find_first_marker(s.clone(), 4), Some(6));
+        assert_eq!(find_first_marker(s, 14), Some(23));
+    }
+
+    #[test]
+    fn test_find_first_marker_04() {
+        let s = String::from("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg");
+        assert_eq!(find_first_marker(s.clone(), 4), Some(10));
+        assert_eq!(find_first_marker(s, 14), Some(29));
+    }
+
+    #[test]
+    fn test_find_first_marker_05() {
+        let s = String::from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
+        assert_eq!(find_first_marker(s.clone(), 4), Some(11));
+        assert_eq!(find_first_marker(s, 14), Some(26));
+    }
+
+    #[test]
+    fn test_find_all_markers_01() {
+        let s = String::from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
+        dbg!(find_all_markers(s));
+        // assert_eq!(10, 11);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 26);
+    }
+
+    #[test]
+    fn test_run_without_marker() {
+        let input = parse_input("abcabc\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 7: unexpected end of line, expected a start-of-packet marker"
+        );
+        let input = parse_input("abcdefghijklmabcdefghijklm\n");
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 1, column 27: unexpected end of line, expected a start-of-message marker"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day06.example();
+        assert_eq!(Day06.part1(example).unwrap().to_string(), "11");
+        assert_eq!(Day06.part2(example).unwrap().to_string(), "26");
+    }
+}
@@ -1,17 +1,37 @@
-fn parse_input(input: Option<&str>) -> Vec<String> {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
-    let output = input
-        .strip_suffix('\n')
-        .unwrap()
-        .split('\n')
-        .map(|o| o.to_string())
-        .collect::<Vec<String>>();
-
-    output
-}
+//! Day 7: No Space Left On Device.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    $ cd /
+    $ ls
+    dir a
+    14848514 b.txt
+    8504156 c.dat
+    dir d
+    $ cd a
+    $ ls
+    dir e
+    29116 f
+    2557 g
+    62596 h.lst
+    $ cd e
+    $ ls
+    584 i
+    $ cd ..
+    $ cd ..
+    $ cd d
+    $ ls
+    4060174 j
+    8033020 d.log
+    5626152 d.ext
+    7214296 k
+    "
+);
 
 #[derive(Debug, PartialEq)]
 struct Tree {
@@ -28,7 +48,7 @@ impl Tree {
     }
 
     fn find_parent(&self, child: &str) -> String {
-        dbg!(child);
+        trace!(?child);
 
         let link = self
             .links
@@ -152,11 +172,23 @@ fn set_new_dir(current_dir: &mut String, new: &str) {
     current_dir.push_str(new);
 }
 
-fn parse_line(tree: &mut Tree, input: &str, current_dir: &mut String, current_level: &mut usize) {
+fn parse_line(
+    tree: &mut Tree,
+    index: usize,
+    input: &str,
+    current_dir: &mut String,
+    current_level: &mut usize,
+) -> Result<(), ParseError> {
     let words: Vec<&str> = input.split(' ').collect();
+    let word = |n: usize, expected: &str| {
+        words
+            .get(n)
+            .map(|word| word.trim())
+            .ok_or_else(|| ParseError::end_of_line(index, input, expected))
+    };
     match words[0].trim() {
-        "$" => match words[1].trim() {
-            "cd" => match words[2].trim() {
+        "$" => match word(1, "`cd` or `ls`")? {
+            "cd" => match word(2, "a directory")? {
                 "/" => {
                     let dir = Directory::new("/", 0);
                     *current_dir = "/".to_string();
@@ -166,57 +198,94 @@ fn parse_line(tree: &mut Tree, input: &str, current_dir: &mut String, current_le
                     *current_dir = tree.find_parent(current_dir);
                     *current_level -= 1;
                 }
-                _ => {
-                    set_new_dir(current_dir, words[2].trim());
+                dir => {
+                    set_new_dir(current_dir, dir);
                     *current_level += 1;
                 }
             },
             "ls" => (),
-            _ => unreachable!(),
+            command => return Err(ParseError::new(index, input, command, "`cd` or `ls`")),
         },
         "dir" => {
             let mut dst = current_dir.clone();
-            set_new_dir(&mut dst, words[1].trim());
+            set_new_dir(&mut dst, word(1, "a directory name")?);
             let dir = Directory::new(&dst, *current_level + 1);
             tree.directories.push(dir);
             let link = Link::new(current_dir, &dst);
             tree.links.push(link);
         }
-        _ => {
-            let file = File::new(words[1], words[0].parse().unwrap());
+        size => {
+            let size = parse_token(index, input, size, "`$`, `dir` or a file size")?;
+            let file = File::new(word(1, "a file name")?, size);
             tree.add_file(current_dir, file);
         }
     }
+    Ok(())
 }
 
-fn run(input: Vec<String>) -> usize {
-    const DEVICE_SIZE: usize = 70000000;
-    const FREE_SIZE_REQUIRED: usize = 30000000;
+/// Parse the terminal output into a tree with the directory sizes.
+fn parse_tree(input: Vec<String>) -> Result<Tree, ParseError> {
     let mut tree = Tree::new();
     let mut current_dir = String::new();
     let mut current_level: usize = 0;
-    for line in input {
-        parse_line(&mut tree, &line, &mut current_dir, &mut current_level)
+    for (index, line) in input.iter().enumerate() {
+        parse_line(&mut tree, index, line, &mut current_dir, &mut current_level)?;
     }
     tree.calculate_sizes();
-    dbg!(&tree);
+    debug!(?tree);
+    Ok(tree)
+}
+
+#[instrument(skip_all, fields(day = 7, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    const MAX_DIRECTORIES_SIZE: usize = 100000;
+    let tree = parse_tree(input)?;
+    Ok(tree
+        .directories
+        .iter()
+        .filter(|o| o.size <= MAX_DIRECTORIES_SIZE)
+        .map(|o| o.size)
+        .sum())
+}
+
+#[instrument(skip_all, fields(day = 7, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    const DEVICE_SIZE: usize = 70000000;
+    const FREE_SIZE_REQUIRED: usize = 30000000;
+    let tree = parse_tree(input)?;
     let free = DEVICE_SIZE - tree.directories[0].size;
     let mut dir_sizes: Vec<usize> = tree.directories.iter().map(|o| o.size).collect();
     dir_sizes.sort();
     for del_dir in dir_sizes {
         if free + del_dir > FREE_SIZE_REQUIRED {
-            return del_dir;
+            return Ok(del_dir);
         }
     }
     unreachable!()
 }
 
-fn main() {
-    let input = parse_input(None);
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn name(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
 
-    let answer = run(input);
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
 
-    println!("Player score: {}", answer);
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
 }
 
 #[allow(unused_imports)]
@@ -375,7 +444,7 @@ mod tests {
         let mut current_dir = String::new();
         let mut current_level = 0;
         let input = "$ cd /";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
 
         assert_eq!(
             tree.directories[0],
@@ -394,9 +463,9 @@ mod tests {
         let mut current_dir = String::new();
         let mut current_level = 0;
         let input = "$ cd /";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "dir a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
 
         dbg!(&tree);
         assert_eq!(
@@ -428,11 +497,11 @@ mod tests {
         let mut current_dir = String::new();
         let mut current_level = 0;
         let input = "$ cd /";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "dir a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "$ cd a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
 
         dbg!(&tree);
         assert_eq!(
@@ -466,13 +535,13 @@ mod tests {
         let mut current_dir = String::new();
         let mut current_level = 0;
         let input = "$ cd /";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "dir a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "$ cd a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "$ cd ..";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
 
         dbg!(&tree);
         assert_eq!(
@@ -506,19 +575,19 @@ mod tests {
         let mut current_dir = String::new();
         let mut current_level = 0;
         let input = "$ cd /";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "dir a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "$ cd a";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "dir b";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "$ cd ..";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "12000 my_file.txt";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
         let input = "12500 my_file_2.txt";
-        parse_line(&mut tree, input, &mut current_dir, &mut current_level);
+        parse_line(&mut tree, 0, input, &mut current_dir, &mut current_level).unwrap();
 
         dbg!(&tree);
         assert_eq!(
@@ -551,36 +620,37 @@ mod tests {
     }
 
     #[test]
-    fn test_run() {
-        let input = parse_input(Some(indoc!(
-            "
-            $ cd /
-            $ ls
-            dir a
-            14848514 b.txt
-            8504156 c.dat
-            dir d
-            $ cd a
-            $ ls
-            dir e
-            29116 f
-            2557 g
-            62596 h.lst
-            $ cd e
-            $ ls
-            584 i
-            $ cd ..
-            $ cd ..
-            $ cd d
-            $ ls
-            4060174 j
-            8033020 d.log
-            5626152 d.ext
-            7214296 k
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input);
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
         assert_eq!(answer, 24933642);
     }
+
+    #[test]
+    fn test_run_invalid_line() {
+        let input = parse_input("$ cd /\n$ ls\n14848514 b.txt\n$ rm b.txt\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 4, column 3: unexpected `rm`, expected `cd` or `ls`"
+        );
+        let input = parse_input("$ cd /\n$ ls\n14848514k b.txt\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 1: unexpected `14848514k`, expected `$`, `dir` or a file size"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day07.example();
+        assert_eq!(Day07.part1(example).unwrap().to_string(), "95437");
+        assert_eq!(Day07.part2(example).unwrap().to_string(), "24933642");
+    }
 }
@@ -0,0 +1,326 @@
+//! Day 8: Treetop Tree House.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, Direction, Grid, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::instrument;
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    30373
+    25512
+    65332
+    33549
+    35390
+    "
+);
+
+#[derive(Debug, PartialEq)]
+enum Visible {
+    Yes,
+    No,
+}
+
+type Map = Grid<isize>;
+
+fn parse_map(input: Vec<String>) -> Result<Map, ParseError> {
+    Grid::parse(&input, "a tree height", |height| {
+        height.to_digit(10).map(|height| height as isize)
+    })
+}
+
+/// Whether the tree at `pos` can be seen from the edge of the map in
+/// `direction`, and how many trees it sees that way.
+fn look(pos: &Coord, map: &Map, direction: Direction) -> (Visible, u32) {
+    let origin_tree_size = *map.get_cell(*pos).unwrap();
+    let (x, y) = (pos.x as usize, pos.y as usize);
+    let row = map.row(y).unwrap();
+    // Trees from the closest one to the edge of the map
+    let trees: Vec<isize> = match direction {
+        Direction::Right => row[x + 1..].to_vec(),
+        Direction::Left => row[..x].iter().rev().copied().collect(),
+        Direction::Down => map.column(x).skip(y + 1).copied().collect(),
+        Direction::Up => {
+            let mut trees: Vec<isize> = map.column(x).take(y).copied().collect();
+            trees.reverse();
+            trees
+        }
+    };
+    let mut distance = 0;
+    for size in trees {
+        distance += 1;
+        if size >= origin_tree_size {
+            return (Visible::No, distance);
+        }
+    }
+    (Visible::Yes, distance)
+}
+
+fn check_visible(pos: &Coord, map: &Map) -> Visible {
+    let visible = Direction::ALL
+        .iter()
+        .any(|direction| look(pos, map, *direction).0 == Visible::Yes);
+    if visible {
+        Visible::Yes
+    } else {
+        Visible::No
+    }
+}
+
+fn scenic_score(pos: &Coord, map: &Map) -> usize {
+    Direction::ALL
+        .iter()
+        .map(|direction| look(pos, map, *direction).1 as usize)
+        .product()
+}
+
+#[instrument(skip_all, fields(day = 8, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let map = parse_map(input)?;
+    let nb_visible = map
+        .coords()
+        .filter(|pos| check_visible(pos, &map) == Visible::Yes)
+        .count();
+    Ok(nb_visible)
+}
+
+#[instrument(skip_all, fields(day = 8, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let map = parse_map(input)?;
+    let solution = map
+        .coords()
+        .map(|pos| scenic_score(&pos, &map))
+        .max()
+        .unwrap_or(0);
+    Ok(solution)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn name(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_visible_directions() {
+        let input = parse_input(indoc!(
+            "
+            30373
+            25512
+            65332
+            33549
+            35390
+            "
+        ));
+        let map = parse_map(input).unwrap();
+        assert_eq!(
+            look(&Coord::new(0, 0), &map, Direction::Up),
+            (Visible::Yes, 0)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 1), &map, Direction::Up),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 2), &map, Direction::Up),
+            (Visible::Yes, 2)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 0), &map, Direction::Down),
+            (Visible::No, 2)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 1), &map, Direction::Down),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 2), &map, Direction::Down),
+            (Visible::Yes, 2)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 0), &map, Direction::Left),
+            (Visible::Yes, 0)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 1), &map, Direction::Left),
+            (Visible::Yes, 0)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 2), &map, Direction::Left),
+            (Visible::Yes, 0)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 0), &map, Direction::Right),
+            (Visible::No, 2)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 1), &map, Direction::Right),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(0, 2), &map, Direction::Right),
+            (Visible::Yes, 4)
+        );
+
+        assert_eq!(
+            look(&Coord::new(1, 1), &map, Direction::Up),
+            (Visible::Yes, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(1, 1), &map, Direction::Down),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(1, 1), &map, Direction::Left),
+            (Visible::Yes, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(1, 1), &map, Direction::Right),
+            (Visible::No, 1)
+        );
+
+        assert_eq!(
+            look(&Coord::new(2, 1), &map, Direction::Up),
+            (Visible::Yes, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(2, 1), &map, Direction::Down),
+            (Visible::No, 2)
+        );
+        assert_eq!(
+            look(&Coord::new(2, 1), &map, Direction::Left),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(2, 1), &map, Direction::Right),
+            (Visible::Yes, 2)
+        );
+
+        assert_eq!(
+            look(&Coord::new(3, 1), &map, Direction::Up),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(3, 1), &map, Direction::Down),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(3, 1), &map, Direction::Left),
+            (Visible::No, 1)
+        );
+        assert_eq!(
+            look(&Coord::new(3, 1), &map, Direction::Right),
+            (Visible::No, 1)
+        );
+    }
+
+    #[test]
+    fn test_visible() {
+        let input = parse_input(indoc!(
+            "
+            30373
+            25512
+            65332
+            33549
+            35390
+            "
+        ));
+        let map = parse_map(input).unwrap();
+        assert_eq!(check_visible(&Coord::new(3, 1), &map), Visible::No);
+        assert_eq!(check_visible(&Coord::new(2, 3), &map), Visible::Yes);
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let input = parse_input(indoc!(
+            "
+            30373
+            25512
+            65332
+            33549
+            35390
+            "
+        ));
+        let map = parse_map(input).unwrap();
+        assert_eq!(scenic_score(&Coord::new(2, 1), &map), 4);
+        assert_eq!(scenic_score(&Coord::new(2, 3), &map), 8);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = parse_input(indoc!(
+            "
+            30373
+            25512
+            65332
+            33549
+            35390
+            "
+        ));
+        let map = parse_map(input).unwrap();
+        dbg!(&map);
+        assert_eq!(map.row(1).unwrap()[2], 5);
+        assert_eq!(map.row(4).unwrap()[4], 0);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 8);
+    }
+
+    #[test]
+    fn test_run_invalid_height() {
+        let input = parse_input("30373\n25512\n65a32\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 3: unexpected `a`, expected a tree height"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day08.example();
+        assert_eq!(Day08.part1(example).unwrap().to_string(), "21");
+        assert_eq!(Day08.part2(example).unwrap().to_string(), "8");
+    }
+}
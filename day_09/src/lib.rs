@@ -0,0 +1,477 @@
+//! Day 9: Rope Bridge.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, Coord, Direction, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::collections::HashSet;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    R 4
+    U 4
+    L 3
+    D 1
+    R 4
+    D 1
+    L 5
+    R 2
+    "
+);
+
+/// Example of the second part, with a longer rope moving further.
+pub const LARGER_EXAMPLE: &str = indoc!(
+    "
+    R 5
+    U 8
+    L 8
+    D 3
+    R 17
+    D 10
+    L 25
+    U 20
+    "
+);
+
+#[derive(Debug, PartialEq, Clone)]
+enum Move {
+    Up(u32),
+    Down(u32),
+    Left(u32),
+    Right(u32),
+}
+
+impl Move {
+    fn direction(&self) -> (Direction, u32) {
+        match self {
+            Move::Up(steps) => (Direction::Up, *steps),
+            Move::Down(steps) => (Direction::Down, *steps),
+            Move::Left(steps) => (Direction::Left, *steps),
+            Move::Right(steps) => (Direction::Right, *steps),
+        }
+    }
+}
+
+fn parse_line(input: Vec<String>) -> Result<Vec<Move>, ParseError> {
+    let moves = input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let v = line.split(' ').collect::<Vec<&str>>();
+            let steps = || {
+                let steps = v
+                    .get(1)
+                    .ok_or_else(|| ParseError::end_of_line(index, line, "a number of steps"))?;
+                parse_token(index, line, steps, "a number of steps")
+            };
+            match v[0] {
+                "U" => Ok(Move::Up(steps()?)),
+                "D" => Ok(Move::Down(steps()?)),
+                "L" => Ok(Move::Left(steps()?)),
+                "R" => Ok(Move::Right(steps()?)),
+                _ => Err(ParseError::new(index, line, v[0], "`U`, `D`, `L` or `R`")),
+            }
+        })
+        .collect::<Result<Vec<Move>, ParseError>>()?;
+    Ok(moves)
+}
+
+/// Move the head of the rope, each knot following the previous one when
+/// they no longer touch. Return the positions of the tail after each step.
+fn shift(rope: &mut [Coord], mov: &Move) -> Vec<Coord> {
+    const LENGTH: usize = 1;
+    let (direction, steps) = mov.direction();
+    let mut tail = Vec::new();
+    for _ in 0..steps {
+        rope[0] += direction.vector();
+        for i in 1..rope.len() {
+            if rope[i - 1].chebyshev(&rope[i]) > LENGTH {
+                rope[i] += (rope[i - 1] - rope[i]).signum();
+            }
+        }
+        tail.push(*rope.last().unwrap());
+    }
+    tail
+}
+
+/// Number of positions visited by the tail of a rope of `nb_knots` knots.
+fn simulate(input: Vec<String>, nb_knots: usize) -> Result<usize, ParseError> {
+    let moves = parse_line(input)?;
+    debug!(?moves);
+    let mut rope = vec![Coord::new(0, 0); nb_knots];
+    let mut visited = HashSet::from([Coord::new(0, 0)]);
+
+    for mov in &moves {
+        visited.extend(shift(&mut rope, mov));
+    }
+    for i in &rope {
+        trace!(x = i.x, y = i.y, "knot");
+    }
+    Ok(visited.len())
+}
+
+#[instrument(skip_all, fields(day = 9, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    simulate(input, 2)
+}
+
+#[instrument(skip_all, fields(day = 9, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    simulate(input, 10)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn name(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut rope = vec![Coord::new(0, 0), Coord::new(0, 0)];
+        shift(&mut rope, &Move::Right(3));
+        assert_eq!(rope[0].x, 3isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 2isize);
+        assert_eq!(rope[1].y, 0isize);
+
+        shift(&mut rope, &Move::Up(4));
+        assert_eq!(rope[0].x, 3isize);
+        assert_eq!(rope[0].y, -4isize);
+        assert_eq!(rope[1].x, 3isize);
+        assert_eq!(rope[1].y, -3isize);
+
+        shift(&mut rope, &Move::Left(3));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -4isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -4isize);
+
+        shift(&mut rope, &Move::Down(4));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        shift(&mut rope, &Move::Up(5));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -5isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -4isize);
+
+        shift(&mut rope, &Move::Right(5));
+        assert_eq!(rope[0].x, 5isize);
+        assert_eq!(rope[0].y, -5isize);
+        assert_eq!(rope[1].x, 4isize);
+        assert_eq!(rope[1].y, -5isize);
+
+        shift(&mut rope, &Move::Down(5));
+        assert_eq!(rope[0].x, 5isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 5isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        shift(&mut rope, &Move::Left(5));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, 0isize);
+
+        shift(&mut rope, &Move::Right(5));
+        assert_eq!(rope[0].x, 5isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 4isize);
+        assert_eq!(rope[1].y, 0isize);
+
+        shift(&mut rope, &Move::Left(5));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, 0isize);
+
+        shift(&mut rope, &Move::Up(9));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -9isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -8isize);
+
+        shift(&mut rope, &Move::Down(9));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        shift(&mut rope, &Move::Up(2));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -2isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        shift(&mut rope, &Move::Right(2));
+        assert_eq!(rope[0].x, 2isize);
+        assert_eq!(rope[0].y, -2isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Left(2));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -2isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Up(1));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Right(1));
+        assert_eq!(rope[0].x, 1isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Right(1));
+        assert_eq!(rope[0].x, 2isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Down(1));
+        assert_eq!(rope[0].x, 2isize);
+        assert_eq!(rope[0].y, -2isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Down(1));
+        assert_eq!(rope[0].x, 2isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Left(1));
+        assert_eq!(rope[0].x, 1isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Left(1));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Up(1));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -2isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -2isize);
+
+        shift(&mut rope, &Move::Down(2));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 0isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(1, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Up(2));
+        assert_eq!(rope[0].x, 1isize);
+        assert_eq!(rope[0].y, -5isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -4isize);
+
+        let mut rope = vec![Coord::new(1, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Up(2));
+        assert_eq!(rope[0].x, 1isize);
+        assert_eq!(rope[0].y, -5isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -4isize);
+
+        let mut rope = vec![Coord::new(1, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Down(3));
+        assert_eq!(rope[0].x, 1isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Left(3));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Right(3));
+        assert_eq!(rope[0].x, 6isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 5isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Left(3));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Right(3));
+        assert_eq!(rope[0].x, 6isize);
+        assert_eq!(rope[0].y, -1isize);
+        assert_eq!(rope[1].x, 5isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Up(3));
+        assert_eq!(rope[0].x, 3isize);
+        assert_eq!(rope[0].y, -4isize);
+        assert_eq!(rope[1].x, 3isize);
+        assert_eq!(rope[1].y, -3isize);
+
+        let mut rope = vec![Coord::new(3, -1), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Down(1));
+        assert_eq!(rope[0].x, 3isize);
+        assert_eq!(rope[0].y, 0isize);
+        assert_eq!(rope[1].x, 3isize);
+        assert_eq!(rope[1].y, -1isize);
+
+        let mut rope = vec![Coord::new(3, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Left(3));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -3isize);
+
+        let mut rope = vec![Coord::new(3, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Right(3));
+        assert_eq!(rope[0].x, 6isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 5isize);
+        assert_eq!(rope[1].y, -3isize);
+
+        let mut rope = vec![Coord::new(3, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Left(3));
+        assert_eq!(rope[0].x, 0isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 1isize);
+        assert_eq!(rope[1].y, -3isize);
+
+        let mut rope = vec![Coord::new(3, -3), Coord::new(2, -2)];
+        shift(&mut rope, &Move::Right(3));
+        assert_eq!(rope[0].x, 6isize);
+        assert_eq!(rope[0].y, -3isize);
+        assert_eq!(rope[1].x, 5isize);
+        assert_eq!(rope[1].y, -3isize);
+    }
+
+    #[test]
+    fn test_distance() {
+        let head = Coord::new(0, 0);
+        let tail = Coord::new(0, 0);
+        assert_eq!(head.chebyshev(&tail), 0);
+        let head = Coord::new(3, 0);
+        let tail = Coord::new(0, 0);
+        assert_eq!(head.chebyshev(&tail), 3);
+        let head = Coord::new(1, -1);
+        let tail = Coord::new(0, 0);
+        assert_eq!(head.chebyshev(&tail), 1);
+        let head = Coord::new(2, -2);
+        let tail = Coord::new(1, -1);
+        assert_eq!(head.chebyshev(&tail), 1);
+        let head = Coord::new(3, -1);
+        let tail = Coord::new(1, -1);
+        assert_eq!(head.chebyshev(&tail), 2);
+        let head = Coord::new(3, -2);
+        let tail = Coord::new(1, -1);
+        assert_eq!(head.chebyshev(&tail), 2);
+        let head = Coord::new(3, -3);
+        let tail = Coord::new(1, -1);
+        assert_eq!(head.chebyshev(&tail), 2);
+    }
+
+    #[test]
+    fn test_simple_move() {
+        let input = parse_input(indoc!(
+            "
+            R 5
+            U 8
+            "
+        ));
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 1);
+    }
+
+    #[test]
+    fn test_part2_larger_example() {
+        let input = parse_input(LARGER_EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 36);
+    }
+
+    #[test]
+    fn test_run_invalid_move() {
+        let input = parse_input("R 4\nX 4\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 1: unexpected `X`, expected `U`, `D`, `L` or `R`"
+        );
+        let input = parse_input("R 4\nU -4\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 3: unexpected `-4`, expected a number of steps"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day09.example();
+        assert_eq!(Day09.part1(example).unwrap().to_string(), "13");
+        assert_eq!(Day09.part2(example).unwrap().to_string(), "1");
+    }
+}
@@ -0,0 +1,409 @@
+//! Day 10: Cathode-Ray Tube.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    addx 15
+    addx -11
+    addx 6
+    addx -3
+    addx 5
+    addx -1
+    addx -8
+    addx 13
+    addx 4
+    noop
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx 5
+    addx -1
+    addx -35
+    addx 1
+    addx 24
+    addx -19
+    addx 1
+    addx 16
+    addx -11
+    noop
+    noop
+    addx 21
+    addx -15
+    noop
+    noop
+    addx -3
+    addx 9
+    addx 1
+    addx -3
+    addx 8
+    addx 1
+    addx 5
+    noop
+    noop
+    noop
+    noop
+    noop
+    addx -36
+    noop
+    addx 1
+    addx 7
+    noop
+    noop
+    noop
+    addx 2
+    addx 6
+    noop
+    noop
+    noop
+    noop
+    noop
+    addx 1
+    noop
+    noop
+    addx 7
+    addx 1
+    noop
+    addx -13
+    addx 13
+    addx 7
+    noop
+    addx 1
+    addx -33
+    noop
+    noop
+    noop
+    addx 2
+    noop
+    noop
+    noop
+    addx 8
+    noop
+    addx -1
+    addx 2
+    addx 1
+    noop
+    addx 17
+    addx -9
+    addx 1
+    addx 1
+    addx -3
+    addx 11
+    noop
+    noop
+    addx 1
+    noop
+    addx 1
+    noop
+    noop
+    addx -13
+    addx -19
+    addx 1
+    addx 3
+    addx 26
+    addx -30
+    addx 12
+    addx -1
+    addx 3
+    addx 1
+    noop
+    noop
+    noop
+    addx -9
+    addx 18
+    addx 1
+    addx 2
+    noop
+    noop
+    addx 9
+    noop
+    noop
+    noop
+    addx -1
+    addx 2
+    addx -37
+    addx 1
+    addx 3
+    noop
+    addx 15
+    addx -21
+    addx 22
+    addx -6
+    addx 1
+    noop
+    addx 2
+    addx 1
+    noop
+    addx -10
+    noop
+    noop
+    addx 20
+    addx 1
+    addx 2
+    addx 2
+    addx -6
+    addx -11
+    noop
+    noop
+    noop
+    "
+);
+
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_line(input: Vec<String>) -> Result<Vec<Instruction>, ParseError> {
+    let instructions = input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let v = line.split(' ').collect::<Vec<&str>>();
+            match v[0] {
+                "noop" => Ok(Instruction::Noop),
+                "addx" => {
+                    let value = v
+                        .get(1)
+                        .ok_or_else(|| ParseError::end_of_line(index, line, "a value"))?;
+                    Ok(Instruction::Addx(parse_token(
+                        index, line, value, "a value",
+                    )?))
+                }
+                _ => Err(ParseError::new(index, line, v[0], "`noop` or `addx`")),
+            }
+        })
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+    Ok(instructions)
+}
+
+fn check_register(tick: i32, register_x: &i32, signal: &mut i32) {
+    trace!(tick, register_x);
+    match tick {
+        20 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        60 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        100 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        140 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        180 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        220 => {
+            *signal += tick * register_x;
+            trace!(tick, register_x, signal);
+        }
+        _ => (),
+    }
+}
+
+#[instrument(skip_all, fields(day = 10, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<i32, ParseError> {
+    let instructions = parse_line(input)?;
+    let mut register_x: i32 = 1;
+    debug!(?instructions);
+
+    let mut ticks = 1..;
+    let mut instructions_counter = 0;
+    let mut signal = 0;
+
+    loop {
+        let mut tick = ticks.next().unwrap();
+        check_register(tick, &register_x, &mut signal);
+
+        match instructions.get(instructions_counter) {
+            Some(Instruction::Noop) => (),
+            Some(Instruction::Addx(value)) => {
+                tick = ticks.next().unwrap();
+                check_register(tick, &register_x, &mut signal);
+                register_x += value;
+            }
+            None => break Ok(signal),
+        };
+        instructions_counter += 1;
+    }
+    // signal
+}
+
+fn draw_pixel(crt: &mut Vec<char>, register_x: &mut i32, tick: i32) {
+    let sprite = *register_x - 1..=*register_x + 1;
+    if tick == 0 {
+        crt.push('\n');
+    }
+
+    if sprite.contains(&(tick)) {
+        crt.push('#');
+    } else {
+        crt.push('.');
+    }
+}
+
+#[instrument(skip_all, fields(day = 10, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<String, ParseError> {
+    let instructions = parse_line(input)?;
+    let mut register_x: i32 = 1;
+    debug!(?instructions);
+
+    let mut ticks = (0..40).cycle();
+    let mut instructions_counter = 0;
+    let mut signal = 0;
+    let mut crt: Vec<char> = Vec::new();
+
+    loop {
+        let mut tick = ticks.next().unwrap();
+        check_register(tick, &register_x, &mut signal);
+        draw_pixel(&mut crt, &mut register_x, tick);
+
+        match instructions.get(instructions_counter) {
+            Some(Instruction::Noop) => (),
+            Some(Instruction::Addx(value)) => {
+                tick = ticks.next().unwrap();
+                check_register(tick, &register_x, &mut signal);
+                draw_pixel(&mut crt, &mut register_x, tick);
+                register_x += value;
+            }
+            None => {
+                crt.pop();
+                crt.remove(0);
+                let output = crt
+                    .iter()
+                    .map(|o| o.to_string())
+                    .collect::<Vec<String>>()
+                    .join("");
+
+                break Ok(output);
+            }
+        };
+        instructions_counter += 1;
+    }
+    // signal
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn name(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1_small_program() {
+        let input = parse_input(indoc!(
+            "
+            noop
+            addx 3
+            addx -5
+            "
+        ));
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 0);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 13140);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(
+            answer,
+            indoc!(
+                "
+                ##..##..##..##..##..##..##..##..##..##..
+                ###...###...###...###...###...###...###.
+                ####....####....####....####....####....
+                #####.....#####.....#####.....#####.....
+                ######......######......######......####
+                #######.......#######.......#######.....
+                "
+            )
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_instruction() {
+        let input = parse_input("noop\naddx 3\nsubx 5\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 1: unexpected `subx`, expected `noop` or `addx`"
+        );
+        let input = parse_input("noop\naddx\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 5: unexpected end of line, expected a value"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day10.example();
+        assert_eq!(Day10.part1(example).unwrap().to_string(), "13140");
+        assert_eq!(
+            Day10.part2(example).unwrap().to_string(),
+            indoc!(
+                "
+                ##..##..##..##..##..##..##..##..##..##..
+                ###...###...###...###...###...###...###.
+                ####....####....####....####....####....
+                #####.....#####.....#####.....#####.....
+                ######......######......######......####
+                #######.......#######.......#######.....
+                "
+            )
+        );
+    }
+}
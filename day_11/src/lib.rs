@@ -0,0 +1,429 @@
+//! Day 11: Monkey in the Middle.
+
+use std::collections::VecDeque;
+
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::multispace1,
+    combinator::cut,
+    error::{context, ErrorKind, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair},
+    *,
+};
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    Monkey 0:
+      Starting items: 79, 98
+      Operation: new = old * 19
+      Test: divisible by 23
+        If true: throw to monkey 2
+        If false: throw to monkey 3
+
+    Monkey 1:
+      Starting items: 54, 65, 75, 74
+      Operation: new = old + 6
+      Test: divisible by 19
+        If true: throw to monkey 2
+        If false: throw to monkey 0
+
+    Monkey 2:
+      Starting items: 79, 60, 97
+      Operation: new = old * old
+      Test: divisible by 13
+        If true: throw to monkey 1
+        If false: throw to monkey 3
+
+    Monkey 3:
+      Starting items: 74
+      Operation: new = old + 3
+      Test: divisible by 17
+        If true: throw to monkey 0
+        If false: throw to monkey 1
+    "
+);
+
+type Res<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+#[derive(Debug, PartialEq)]
+enum Operation {
+    Add((Value, Value)),
+    Mul((Value, Value)),
+}
+
+#[derive(Debug, PartialEq)]
+enum Throw {
+    True(u64),
+    False(u64),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Value {
+    Old,
+    N(u64),
+}
+
+#[derive(Debug, PartialEq)]
+struct Monkey {
+    id: u64,
+    items: VecDeque<u64>,
+    operation: Operation,
+    divisible: u64,
+    throws: (Throw, Throw),
+    thrown_count: usize,
+}
+
+fn parse_operation(input: &str) -> Res<'_, Operation> {
+    let (input, op) = context("`*` or `+`", alt((tag("*"), tag("+"))))(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, val) = context(
+        "`old` or a number",
+        alt((
+            tag("old").map(|_| Value::Old),
+            nom::character::complete::u64.map(Value::N),
+        )),
+    )(input)?;
+
+    let op = match op {
+        "*" => Operation::Mul((Value::Old, val)),
+        "+" => Operation::Add((Value::Old, val)),
+        _ => unreachable!(),
+    };
+
+    Ok((input, op))
+}
+
+fn parse_monkey(input: &str) -> Res<'_, Monkey> {
+    // Ouch nom is really cool !
+    let (input, (_, id)) = delimited(
+        context("`Monkey`", tag("Monkey")),
+        pair(
+            multispace1,
+            context("a monkey id", nom::character::complete::u64),
+        ),
+        context("`:`", tag(":")),
+    )(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context("`Starting items:`", tag("Starting items: "))(input)?;
+    let (input, items) = separated_list1(
+        tag(", "),
+        context("a worry level", nom::character::complete::u64),
+    )(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context("`Operation: new = old`", tag("Operation: new = old "))(input)?;
+    let (input, operation) = parse_operation(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context("`Test: divisible by`", tag("Test: divisible by "))(input)?;
+    let (input, divisible) = context("a divisor", nom::character::complete::u64)(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context(
+        "`If true: throw to monkey`",
+        tag("If true: throw to monkey "),
+    )(input)?;
+    let (input, throw_val1) = context("a monkey id", nom::character::complete::u64)(input)?;
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context(
+        "`If false: throw to monkey`",
+        tag("If false: throw to monkey "),
+    )(input)?;
+    let (input, throw_val2) = context("a monkey id", nom::character::complete::u64)(input)?;
+    Ok((
+        input,
+        Monkey {
+            id,
+            items: VecDeque::from(items),
+            operation,
+            divisible,
+            throws: (Throw::True(throw_val1), Throw::False(throw_val2)),
+            thrown_count: 0,
+        },
+    ))
+}
+
+/// Locate a nom error within the whole `input`.
+fn to_parse_error(input: &str, e: Err<VerboseError<&str>>) -> ParseError {
+    let e = match e {
+        Err::Error(e) | Err::Failure(e) => e,
+        Err::Incomplete(_) => return ParseError::at_offset(input, input.len(), "a monkey"),
+    };
+    let rest = e.errors.first().map_or("", |(rest, _)| rest);
+    let expected = e
+        .errors
+        .iter()
+        .find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(*context),
+            VerboseErrorKind::Nom(ErrorKind::MultiSpace) => Some("a line break"),
+            _ => None,
+        })
+        .unwrap_or("a monkey");
+    ParseError::at_offset(input, input.len() - rest.len(), expected)
+}
+
+/// How the worry level is kept manageable after each inspection.
+#[derive(Debug, Clone, Copy)]
+enum Relief {
+    /// Divided by 3 as the item is not damaged
+    Divide,
+    /// Modulo the product of the divisors, which keeps every test result
+    Modulo(u64),
+}
+
+fn monkey_action(monkey: &mut Monkey, relief: Relief) -> Vec<(u64, u64)> {
+    let mut throws_items: Vec<(u64, u64)> = Vec::new();
+    for _ in 0..monkey.items.len() {
+        let item = monkey.items.pop_front().unwrap();
+        let worry_level = match monkey.operation {
+            Operation::Add(val) => match val {
+                (Value::Old, Value::Old) => item + item,
+                (Value::Old, Value::N(val)) => item + val,
+                (Value::N(_), _) => unreachable!(),
+            },
+            Operation::Mul(val) => match val {
+                (Value::Old, Value::Old) => item * item,
+                (Value::Old, Value::N(val)) => item * val,
+                (Value::N(_), _) => unreachable!(),
+            },
+        };
+        let worry_level = match relief {
+            Relief::Divide => worry_level / 3,
+            Relief::Modulo(keep_calm) => worry_level % keep_calm,
+        };
+
+        if worry_level % monkey.divisible == 0 {
+            match monkey.throws.0 {
+                Throw::True(val) => {
+                    throws_items.push((worry_level, val));
+                    monkey.thrown_count += 1;
+                }
+                _ => unreachable!(),
+            }
+        } else {
+            match monkey.throws.1 {
+                Throw::False(val) => {
+                    throws_items.push((worry_level, val));
+                    monkey.thrown_count += 1;
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+    throws_items
+}
+
+fn dispatch_throws_items(monkeys: &mut [Monkey], throws_items: Vec<(u64, u64)>) {
+    for (item, monkey_index) in throws_items {
+        monkeys[monkey_index as usize].items.push_back(item);
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let input = input.trim_end();
+    let (rest, monkeys) = separated_list1(tag("\n\n"), cut(parse_monkey))(input)
+        .map_err(|e| to_parse_error(input, e))?;
+    if !rest.is_empty() {
+        return Err(ParseError::at_offset(
+            input,
+            input.len() - rest.len(),
+            "an empty line between monkeys",
+        ));
+    }
+
+    // The numbers the monkeys play with have to be valid too, each monkey
+    // being the block of lines at the same index
+    let mut start = 0;
+    for (monkey, block) in monkeys.iter().zip(input.split("\n\n")) {
+        let offset = |text: &str| start + block.find(text).map_or(0, |i| i + text.len());
+        if monkey.divisible == 0 {
+            return Err(ParseError::at_offset(
+                input,
+                offset("divisible by "),
+                "a divisor other than 0",
+            ));
+        }
+        let targets = [
+            ("If true: throw to monkey ", &monkey.throws.0),
+            ("If false: throw to monkey ", &monkey.throws.1),
+        ];
+        for (text, throw) in targets {
+            let (Throw::True(target) | Throw::False(target)) = throw;
+            if *target as usize >= monkeys.len() {
+                let expected = format!("a monkey below {}", monkeys.len());
+                return Err(ParseError::at_offset(input, offset(text), &expected));
+            }
+        }
+        start += block.len() + 2;
+    }
+    Ok(monkeys)
+}
+
+/// Monkey business after `rounds` rounds.
+fn play(mut monkeys: Vec<Monkey>, rounds: usize, relief: Relief) -> usize {
+    for _ in 0..rounds {
+        for monkey_index in 0..monkeys.len() {
+            let throws_items = monkey_action(&mut monkeys[monkey_index], relief);
+            dispatch_throws_items(&mut monkeys, throws_items);
+        }
+    }
+
+    monkeys.sort_by_key(|monkey| monkey.thrown_count);
+    debug!(?monkeys);
+
+    monkeys
+        .iter()
+        .rev()
+        .take(2)
+        .map(|m| m.thrown_count)
+        .product()
+}
+
+#[instrument(skip_all, fields(day = 11, part = 1))]
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let monkeys = parse_monkeys(input)?;
+    Ok(play(monkeys, 20, Relief::Divide))
+}
+
+#[instrument(skip_all, fields(day = 11, part = 2))]
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let monkeys = parse_monkeys(input)?;
+    let keep_calm = monkeys
+        .iter()
+        .map(|monkey| monkey.divisible)
+        .product::<u64>();
+    Ok(play(monkeys, 10000, Relief::Modulo(keep_calm)))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    fn name(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(input)?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_monkey() {
+        let input = indoc!(
+            "
+            Monkey 0:
+              Starting items: 79, 98
+              Operation: new = old * 19
+              Test: divisible by 23
+                If true: throw to monkey 2
+                If false: throw to monkey 3
+            "
+        );
+        let parsed = parse_monkey(input).unwrap();
+        assert_eq!(
+            parsed.1,
+            Monkey {
+                id: 0,
+                items: VecDeque::from(vec![79, 98]),
+                operation: Operation::Mul((Value::Old, Value::N(19))),
+                divisible: 23,
+                throws: (Throw::True(2), Throw::False(3)),
+                thrown_count: 0,
+            },
+        );
+    }
+
+    #[test]
+    fn test_monkey_action() {
+        let input = indoc!(
+            "
+            Monkey 0:
+              Starting items: 79, 98
+              Operation: new = old * 19
+              Test: divisible by 23
+                If true: throw to monkey 2
+                If false: throw to monkey 3
+            "
+        );
+        let mut parsed = parse_monkey(input).unwrap();
+        let throws_items = monkey_action(&mut parsed.1, Relief::Divide);
+        assert_eq!(parsed.1.items, []);
+        assert_eq!(throws_items, [(500, 3,), (620, 3,)]);
+        assert_eq!(parsed.1.thrown_count, 2);
+    }
+
+    #[test]
+    fn test_part1() {
+        let answer = part1(EXAMPLE).unwrap();
+        assert_eq!(answer, 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        let answer = part2(EXAMPLE).unwrap();
+        assert_eq!(answer, 2713310158);
+    }
+
+    #[test]
+    fn test_run_invalid_monkey() {
+        let input = EXAMPLE.replace("new = old + 6", "new = old - 6");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 10, column 24: unexpected `-`, expected `*` or `+`"
+        );
+        let input = EXAMPLE.replace("divisible by 13", "divisible by x");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 18, column 22: unexpected `x`, expected a divisor"
+        );
+        let input = EXAMPLE.replace("\n\nMonkey 3", "\nMonkey 3");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 20, column 32: unexpected end of line, expected an empty line between monkeys"
+        );
+        let input = EXAMPLE.replace("divisible by 13", "divisible by 0");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 18, column 22: unexpected `0`, expected a divisor other than 0"
+        );
+        let input = EXAMPLE.replace("If false: throw to monkey 0", "If false: throw to monkey 9");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 13, column 31: unexpected `9`, expected a monkey below 4"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day11.example();
+        assert_eq!(Day11.part1(example).unwrap().to_string(), "10605");
+        assert_eq!(Day11.part2(example).unwrap().to_string(), "2713310158");
+    }
+}
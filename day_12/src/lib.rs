@@ -0,0 +1,267 @@
+//! Day 12: Hill Climbing Algorithm.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, Grid, ParseError, Solution, SolveError};
+use indoc::indoc;
+use petgraph::algo::dijkstra;
+use petgraph::dot::Dot;
+use petgraph::prelude::*;
+use std::fmt;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    Sabqponm
+    abcryxxl
+    accszExk
+    acctuvwj
+    abdefghi
+    "
+);
+
+#[derive(Debug, Clone)]
+enum Cell {
+    Start,
+    End,
+    Elevation(isize),
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Start => 'S',
+            Cell::End => 'E',
+            Cell::Elevation(elevation) => (b'a' + *elevation as u8) as char,
+        };
+        write!(f, "{}", c)
+    }
+}
+
+fn parse_map(input: &[String]) -> Result<Grid<Cell>, ParseError> {
+    Grid::parse(
+        input,
+        "an elevation from `a` to `z`, `S` or `E`",
+        |c| match c {
+            'S' => Some(Cell::Start),
+            'E' => Some(Cell::End),
+            'a'..='z' => Some(Cell::Elevation((c as u8 - b'a') as isize)),
+            _ => None,
+        },
+    )
+}
+
+/// The heightmap, with its start and end replaced by their elevations, and
+/// the possible steps between its cells.
+struct Hill {
+    map: Grid<Cell>,
+    start: Coord,
+    end: Coord,
+    edges: Vec<(Coord, Coord)>,
+}
+
+fn parse_hill(input: &[String]) -> Result<Hill, ParseError> {
+    let mut map = parse_map(input)?;
+    let mut start: Option<Coord> = None;
+    let mut end: Option<Coord> = None;
+    let mut edges: Vec<(Coord, Coord)> = Vec::new();
+
+    for coord in map.coords() {
+        let cell = map.get_mut_cell(coord).unwrap();
+        match cell {
+            Cell::Start => {
+                start = Some(coord);
+                *cell = Cell::Elevation(0)
+            }
+            Cell::End => {
+                end = Some(coord);
+                *cell = Cell::Elevation(25)
+            }
+            _ => (),
+        }
+    }
+    debug!(?map);
+    debug!(?start, ?end);
+    let last = input.len() - 1;
+    let start = start.ok_or_else(|| ParseError::end_of_line(last, &input[last], "a start `S`"))?;
+    let end = end.ok_or_else(|| ParseError::end_of_line(last, &input[last], "an end `E`"))?;
+
+    for coord in map.coords() {
+        let Some(Cell::Elevation(ec)) = map.get_cell(coord) else {
+            unreachable!()
+        };
+        for neighbor in map.neighbours4(coord) {
+            match map.get_cell(neighbor) {
+                Some(Cell::Elevation(enc)) if enc - ec > 1 => continue,
+                Some(Cell::Elevation(_)) => edges.push((coord, neighbor)),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    Ok(Hill {
+        map,
+        start,
+        end,
+        edges,
+    })
+}
+
+/// Error on the end `E`, when the climb cannot get there.
+fn unreachable_end(input: &[String], end: Coord, expected: &str) -> ParseError {
+    let line = &input[end.y as usize];
+    let (position, _) = line.char_indices().nth(end.x as usize).unwrap();
+    ParseError::new(
+        end.y as usize,
+        line,
+        &line[position..position + 1],
+        expected,
+    )
+}
+
+#[instrument(skip_all, fields(day = 12, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let Hill {
+        start, end, edges, ..
+    } = parse_hill(&input)?;
+
+    let g = DiGraphMap::<Coord, ()>::from_edges(edges);
+    trace!("graph:\n{:?}", Dot::new(&g));
+
+    let res = dijkstra(&g, start, Some(end), |_| 1);
+    res.get(&end)
+        .copied()
+        .ok_or_else(|| unreachable_end(&input, end, "an end `E` reachable from the start"))
+}
+
+#[instrument(skip_all, fields(day = 12, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let Hill {
+        map, end, edges, ..
+    } = parse_hill(&input)?;
+
+    let g = DiGraphMap::<Coord, ()>::from_edges(edges.iter().map(|(a, b)| (*b, *a)));
+    trace!("graph:\n{:?}", Dot::new(&g));
+
+    let res = dijkstra(&g, end, None, |_| 1);
+    trace!(?res);
+
+    let distances_from_end_to_cell_elev_0 = res
+        .iter()
+        .filter_map(
+            |(coord, dist)| match map.get_cell((coord.x, coord.y).into()) {
+                Some(Cell::Elevation(0)) => Some(dist),
+                Some(_) => None,
+                None => None,
+            },
+        )
+        .collect::<Vec<&usize>>();
+
+    debug!(?distances_from_end_to_cell_elev_0);
+
+    distances_from_end_to_cell_elev_0
+        .into_iter()
+        .min()
+        .copied()
+        .ok_or_else(|| unreachable_end(&input, end, "an end `E` reachable from an elevation `a`"))
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn name(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 29);
+    }
+
+    #[test]
+    fn test_run_invalid_map() {
+        let input = parse_input(&EXAMPLE.replace("accszExk", "accs#Exk"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 5: unexpected `#`, expected an elevation from `a` to `z`, `S` or `E`"
+        );
+        let input = parse_input(&EXAMPLE.replace("acctuvwj", "acctuvw"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 4, column 8: unexpected end of line, expected an elevation from `a` to `z`, `S` or `E`"
+        );
+        let input = parse_input(&EXAMPLE.replace("abdefghi", "abdefghij"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 5, column 9: unexpected `j`, expected the end of the line"
+        );
+        let input = parse_input("ab\ncd\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 3: unexpected end of line, expected a start `S`"
+        );
+        let input = parse_input("Sb\ncd\n");
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 3: unexpected end of line, expected an end `E`"
+        );
+    }
+
+    #[test]
+    fn test_run_unreachable_end() {
+        let input = parse_input("Sz\nzE\n");
+        assert_eq!(
+            part1(input.clone()).unwrap_err().to_string(),
+            "line 2, column 2: unexpected `E`, expected an end `E` reachable from the start"
+        );
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 2, column 2: unexpected `E`, expected an end `E` reachable from an elevation `a`"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day12.example();
+        assert_eq!(Day12.part1(example).unwrap().to_string(), "31");
+        assert_eq!(Day12.part2(example).unwrap().to_string(), "29");
+    }
+}
@@ -0,0 +1,218 @@
+//! Day 13: Distress Signal.
+
+use std::cmp::Ordering;
+
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use serde::Deserialize;
+use tracing::{debug, instrument, trace};
+
+pub fn parse_input(input: &str) -> Vec<String> {
+    aoc_common::split_input(input, "\n\n")
+}
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    [1,1,3,1,1]
+    [1,1,5,1,1]
+
+    [[1],[2,3,4]]
+    [[1],4]
+
+    [9]
+    [[8,7,6]]
+
+    [[4,4],4,4]
+    [[4,4],4,4,4]
+
+    [7,7,7,7]
+    [7,7,7]
+
+    []
+    [3]
+
+    [[[]]]
+    [[]]
+
+    [1,[2,[3,[4,[5,6,7]]]],8,9]
+    [1,[2,[3,[4,[5,6,0]]]],8,9]
+    "
+);
+
+#[derive(Deserialize, Clone, Eq, Debug)]
+#[serde(untagged)]
+enum Node {
+    Number(u64),
+    List(Vec<Node>),
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::List(l0), Self::List(r0)) => l0 == r0,
+            (Self::Number(l0), Self::Number(r0)) => l0 == r0,
+            (Self::List(l0), Self::Number(r0)) => l0 == &vec![Node::Number(*r0)],
+            (Self::Number(l0), Self::List(r0)) => &vec![Node::Number(*l0)] == r0,
+        }
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Node::List(a), Node::List(b)) => a.cmp(b),
+            (Node::List(a), Node::Number(b)) => a.cmp(&vec![Node::Number(*b)]),
+            (Node::Number(a), Node::List(b)) => vec![Node::Number(*a)].cmp(b),
+            (Node::Number(a), Node::Number(b)) => a.cmp(b),
+        }
+    }
+}
+
+fn parse_packet(index: usize, line: &str) -> Result<Node, ParseError> {
+    serde_json::from_str::<Node>(line).map_err(|e| {
+        // serde_json columns point at the last character read
+        match line.char_indices().nth(e.column().saturating_sub(1)) {
+            Some((position, c)) if !e.is_eof() => {
+                let token = &line[position..position + c.len_utf8()];
+                ParseError::new(index, line, token, "a packet")
+            }
+            _ => ParseError::end_of_line(index, line, "a packet"),
+        }
+    })
+}
+
+fn parse_groups(input: Vec<String>) -> Result<Vec<Vec<Node>>, ParseError> {
+    let mut groups = Vec::new();
+    for (group_index, group) in input.iter().enumerate() {
+        // Groups are separated by an empty line
+        let items = group
+            .split('\n')
+            .enumerate()
+            .map(|(index, o)| parse_packet(group_index * 3 + index, o))
+            .collect::<Result<Vec<Node>, ParseError>>()?;
+        groups.push(items);
+    }
+    debug!(?groups);
+    Ok(groups)
+}
+
+#[instrument(skip_all, fields(day = 13, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let groups = parse_groups(input)?;
+    let mut res: Vec<usize> = Vec::new();
+
+    for (index, group) in groups.iter().enumerate() {
+        match group[0].cmp(&group[1]) {
+            Ordering::Less => {
+                trace!("{:?} < {:?} --> Ok", group[0], group[1]);
+                res.push(index);
+            }
+            Ordering::Equal => unreachable!(),
+            Ordering::Greater => {
+                trace!("{:?} < {:?} --> Ko", group[0], group[1]);
+            }
+        }
+    }
+
+    res.iter_mut().for_each(|o| *o += 1);
+    debug!(?res);
+
+    Ok(res.iter().sum())
+}
+
+#[instrument(skip_all, fields(day = 13, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let groups = parse_groups(input)?;
+    let node_2 = Node::List(vec![Node::List(vec![Node::Number(2)])]);
+    let node_6 = Node::List(vec![Node::List(vec![Node::Number(6)])]);
+
+    let mut res = groups.iter().flatten().collect::<Vec<&Node>>();
+    res.push(&node_2);
+    res.push(&node_6);
+    res.sort();
+    debug!(?res);
+
+    let i1 = res.iter().position(|o| **o == node_2).unwrap() + 1;
+    let i2 = res.iter().position(|o| **o == node_6).unwrap() + 1;
+    debug!(i1, i2);
+
+    Ok(i1 * i2)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn name(&self) -> &'static str {
+        "Distress Signal"
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 140);
+    }
+
+    #[test]
+    fn test_run_invalid_packet() {
+        let input = parse_input(&EXAMPLE.replace("[[1],4]", "[[1],x]"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 5, column 6: unexpected `x`, expected a packet"
+        );
+        let input = parse_input(&EXAMPLE.replace("[[1],4]", "[[1],4"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 5, column 7: unexpected end of line, expected a packet"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day13.example();
+        assert_eq!(Day13.part1(example).unwrap().to_string(), "13");
+        assert_eq!(Day13.part2(example).unwrap().to_string(), "140");
+    }
+}
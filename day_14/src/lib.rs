@@ -0,0 +1,294 @@
+//! Day 14: Regolith Reservoir.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, Coord, Direction, Grid, ParseError, Solution, SolveError};
+use core::fmt;
+use indoc::indoc;
+use std::collections::HashSet;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    498,4 -> 498,6 -> 496,6
+    503,4 -> 502,4 -> 502,9 -> 494,9
+    "
+);
+
+#[derive(Debug, Clone)]
+enum Cell {
+    Air,
+    Sand,
+    Rock,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Rock => '#',
+            Cell::Air => '.',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+trait Fall {
+    fn check_down(&self, map: &Grid<Cell>) -> bool;
+    fn check_left(&self, map: &Grid<Cell>) -> bool;
+    fn check_right(&self, map: &Grid<Cell>) -> bool;
+}
+
+/// Whether sand can fall into `coord`, the abyss being out of the map.
+fn is_air(map: &Grid<Cell>, coord: Coord) -> bool {
+    match map.get_cell(coord) {
+        None => false,
+        Some(cell) => match cell {
+            Cell::Rock => false,
+            Cell::Sand => false,
+            Cell::Air => true,
+        },
+    }
+}
+
+impl Fall for Coord {
+    fn check_down(&self, map: &Grid<Cell>) -> bool {
+        is_air(map, *self + Direction::Down.vector())
+    }
+
+    fn check_left(&self, map: &Grid<Cell>) -> bool {
+        is_air(
+            map,
+            *self + Direction::Down.vector() + Direction::Left.vector(),
+        )
+    }
+
+    fn check_right(&self, map: &Grid<Cell>) -> bool {
+        is_air(
+            map,
+            *self + Direction::Down.vector() + Direction::Right.vector(),
+        )
+    }
+}
+
+#[derive(Debug)]
+struct Polyline {
+    points: Vec<Coord>,
+}
+
+impl Polyline {
+    fn new() -> Self {
+        Self { points: Vec::new() }
+    }
+
+    fn add(&mut self, coord: Coord) {
+        self.points.push(coord);
+    }
+
+    fn get_all_coords(&self) -> HashSet<Coord> {
+        let mut points: HashSet<Coord> = HashSet::new();
+        for couple in self.points.windows(2) {
+            let (from, to) = (couple[0], couple[1]);
+            let step = (to - from).signum();
+            trace!(?from, ?to, ?step);
+            let mut point = from;
+            points.insert(point);
+            while point != to {
+                point += step;
+                points.insert(point);
+            }
+        }
+        points
+    }
+}
+
+fn parse_line(index: usize, line: &str) -> Result<Polyline, ParseError> {
+    let mut polyline = Polyline::new();
+    let points: Vec<&str> = line.split("->").collect();
+    for point in &points {
+        let point = point.trim();
+        let (x, y) = point
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(index, line, point, "a `x,y` point"))?;
+        polyline.add(
+            (
+                parse_token(index, line, x, "a x coordinate")?,
+                parse_token(index, line, y, "a y coordinate")?,
+            )
+                .into(),
+        );
+    }
+    Ok(polyline)
+}
+
+/// Number of sand units at rest, once sand flows into the abyss or, when
+/// there is a `floor`, once the source is blocked.
+fn pour_sand(input: Vec<String>, floor: bool) -> Result<usize, ParseError> {
+    let rocks: Vec<Polyline> = input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line))
+        .collect::<Result<Vec<Polyline>, ParseError>>()?;
+
+    debug!(?rocks);
+
+    let max_x = rocks
+        .iter()
+        .flat_map(|p| &p.points)
+        .map(|c| c.x)
+        .max()
+        .unwrap();
+
+    let max_y = rocks
+        .iter()
+        .flat_map(|p| &p.points)
+        .map(|c| c.y)
+        .max()
+        .unwrap();
+
+    debug!(max_x, max_y);
+
+    // The floor is 2 below the lowest rock, and sand piles up to 500 + height
+    let (width, height) = if floor {
+        (max_x * 2, max_y + 3)
+    } else {
+        (max_x + 1, max_y + 1)
+    };
+    let mut map = Grid::filled(width as usize, height as usize, Cell::Air);
+
+    for poly in rocks.iter() {
+        for rock in poly.get_all_coords() {
+            trace!(?rock);
+            let place = map.get_mut_cell(rock).unwrap();
+            *place = Cell::Rock;
+        }
+    }
+
+    if floor {
+        for x in 0..width {
+            let cell = map.get_mut_cell((x, height - 1).into()).unwrap();
+            *cell = Cell::Rock;
+        }
+    }
+
+    let origin = Coord::new(500, 0);
+    let mut sand = origin;
+    let mut nb_sand = 0;
+
+    loop {
+        if !floor && sand.y >= max_y {
+            debug!(nb_sand);
+            break;
+        }
+        match (
+            sand.check_left(&map),
+            sand.check_down(&map),
+            sand.check_right(&map),
+        ) {
+            (_, true, _) => sand.move_down(),
+            (true, false, _) => {
+                sand.move_left();
+                sand.move_down();
+            }
+            (false, false, true) => {
+                sand.move_right();
+                sand.move_down();
+            }
+            (false, false, false) => {
+                let s = map.get_mut_cell(sand).unwrap();
+                *s = Cell::Sand;
+                nb_sand += 1;
+                if sand.y == 0 {
+                    debug!(nb_sand);
+                    break;
+                }
+                sand = origin;
+            }
+        }
+    }
+    debug!(?map);
+
+    Ok(nb_sand)
+}
+
+#[instrument(skip_all, fields(day = 14, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    pour_sand(input, false)
+}
+
+#[instrument(skip_all, fields(day = 14, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    pour_sand(input, true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn name(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 24);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 93);
+    }
+
+    #[test]
+    fn test_run_invalid_point() {
+        let input = parse_input(&EXAMPLE.replace("502,4 ->", "502;4 ->"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 10: unexpected `502;4`, expected a `x,y` point"
+        );
+        let input = parse_input(&EXAMPLE.replace("498,6", "498,y"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 14: unexpected `y`, expected a y coordinate"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day14.example();
+        assert_eq!(Day14.part1(example).unwrap().to_string(), "24");
+        assert_eq!(Day14.part2(example).unwrap().to_string(), "93");
+    }
+}
@@ -1,146 +1,80 @@
+//! Day 15: Beacon Exclusion Zone.
+
 #![allow(dead_code)]
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, Coord, Grid, ParseError, Solution, SolveError, Vector2};
 use core::fmt;
-use core::fmt::Debug;
-
-fn parse_input(input: Option<&str>) -> Vec<String> {
-    let input = match input {
-        None => include_str!("../../input.txt"),
-        Some(x) => x,
-    };
-    let output = input
-        .strip_suffix('\n')
-        .unwrap()
-        .split('\n')
-        .map(|o| o.to_string())
-        .collect::<Vec<String>>();
-
-    output
-}
+use indoc::indoc;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+    Sensor at x=9, y=16: closest beacon is at x=10, y=16
+    Sensor at x=13, y=2: closest beacon is at x=15, y=3
+    Sensor at x=12, y=14: closest beacon is at x=10, y=16
+    Sensor at x=10, y=20: closest beacon is at x=10, y=16
+    Sensor at x=14, y=17: closest beacon is at x=10, y=16
+    Sensor at x=8, y=7: closest beacon is at x=2, y=10
+    Sensor at x=2, y=0: closest beacon is at x=2, y=10
+    Sensor at x=0, y=11: closest beacon is at x=2, y=10
+    Sensor at x=20, y=14: closest beacon is at x=25, y=17
+    Sensor at x=17, y=20: closest beacon is at x=21, y=22
+    Sensor at x=16, y=7: closest beacon is at x=15, y=3
+    Sensor at x=14, y=3: closest beacon is at x=15, y=3
+    Sensor at x=20, y=1: closest beacon is at x=15, y=3
+    "
+);
 
-struct Map {
-    height: usize,
-    width: usize,
-    data: Vec<Cell>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Cell {
+    Signal,
+    Nosignal,
+    Sensor,
+    Beacon,
+    Origin,
 }
 
-impl Map {
-    fn new(width: usize, height: usize) -> Self {
-        let mut data = Vec::new();
-        for _ in 0..height {
-            for _ in 0..width {
-                data.push(Cell::Nosignal);
-            }
-        }
-        Self {
-            height,
-            width,
-            data,
-        }
-    }
-
-    fn bound(&self, coord: &Coord) -> bool {
-        coord.x < self.width as isize
-            && coord.y < self.height as isize
-            && coord.x >= 0
-            && coord.y >= 0
-    }
-
-    fn get_cell(&self, coord: Coord) -> Option<&Cell> {
-        if !self.bound(&coord) {
-            return None;
-        }
-        Some(&self.data[coord.y as usize * self.width + coord.x as usize])
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Signal => '#',
+            Cell::Nosignal => '.',
+            Cell::Sensor => 'S',
+            Cell::Beacon => 'B',
+            Cell::Origin => '*',
+        };
+        write!(f, "{}", c)
     }
+}
 
-    fn get_mut_cell(&mut self, coord: Coord) -> Option<&mut Cell> {
-        if !self.bound(&coord) {
-            return None;
-        }
-        Some(&mut self.data[coord.y as usize * self.width + coord.x as usize])
-    }
+trait WriteCell {
+    fn write_cell(&mut self, coord: Coord, value: Cell) -> Option<()>;
+}
 
+impl WriteCell for Grid<Cell> {
     fn write_cell(&mut self, coord: Coord, value: Cell) -> Option<()> {
-        if !self.bound(&coord) {
-            return None;
-        }
+        let cell = self.get_mut_cell(coord)?;
         if value == Cell::Signal {
-            match self.data[coord.y as usize * self.width + coord.x as usize] {
+            match cell {
                 Cell::Nosignal => {
-                    self.data[coord.y as usize * self.width + coord.x as usize] = value;
+                    *cell = value;
                     return Some(());
                 }
                 Cell::Signal => return Some(()),
                 Cell::Beacon => return Some(()),
                 Cell::Sensor => return Some(()),
                 Cell::Origin => {
-                    self.data[coord.y as usize * self.width + coord.x as usize] = value;
+                    *cell = value;
                     return Some(());
                 }
             };
         }
-        self.data[coord.y as usize * self.width + coord.x as usize] = value;
+        *cell = value;
         Some(())
     }
 }
 
-impl Debug for Map {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "{}x{} grid:", self.width, self.height)?;
-        for y in 0..self.height {
-            let mut line = Vec::new();
-            for x in 0..self.width {
-                let cell = self
-                    .get_cell((x.try_into().unwrap(), y.try_into().unwrap()).into())
-                    .unwrap();
-                let c = match cell {
-                    Cell::Signal => '#',
-                    Cell::Nosignal => '.',
-                    Cell::Sensor => 'S',
-                    Cell::Beacon => 'B',
-                    Cell::Origin => '*',
-                };
-                line.push(c.to_string());
-            }
-            write!(f, "{}", line.join(""))?;
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum Cell {
-    Signal,
-    Nosignal,
-    Sensor,
-    Beacon,
-    Origin,
-}
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-struct Coord {
-    x: isize,
-    y: isize,
-}
-
-impl Coord {
-    fn new(x: isize, y: isize) -> Self {
-        Self { x, y }
-    }
-
-    fn mdist(&self, other: &Coord) -> usize {
-        ((other.x - self.x).abs() + (other.y - self.y).abs())
-            .try_into()
-            .unwrap()
-    }
-}
-
-impl From<(isize, isize)> for Coord {
-    fn from((x, y): (isize, isize)) -> Self {
-        Self { x, y }
-    }
-}
-
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 struct Sensor {
     cell: Coord,
@@ -199,26 +133,35 @@ impl Sensor {
     }
 }
 
-fn parse_line(line: String) -> Sensor {
-    let line_csv = line
-        .replace("Sensor at x=", "")
-        .replace(": closest beacon is at x=", ",")
-        .replace("y=", "");
+fn parse_position(index: usize, line: &str, position: &str) -> Result<Coord, ParseError> {
+    let (x, y) = position
+        .strip_prefix("x=")
+        .and_then(|position| position.split_once(", y="))
+        .ok_or_else(|| ParseError::new(index, line, position, "`x=<x>, y=<y>`"))?;
+    Ok((
+        parse_token(index, line, x, "a x coordinate")?,
+        parse_token(index, line, y, "a y coordinate")?,
+    )
+        .into())
+}
 
-    let coordinates: Vec<&str> = line_csv.split(',').collect();
+fn parse_line(index: usize, line: &str) -> Result<Sensor, ParseError> {
+    let (sensor, beacon) = line
+        .strip_prefix("Sensor at ")
+        .and_then(|line| line.split_once(": closest beacon is at "))
+        .ok_or_else(|| {
+            ParseError::new(
+                index,
+                line,
+                line,
+                "`Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`",
+            )
+        })?;
     let sensor = Sensor {
-        cell: (
-            coordinates[0].trim().parse().unwrap(),
-            coordinates[1].trim().parse().unwrap(),
-        )
-            .into(),
-        beacon_cell: (
-            coordinates[2].trim().parse().unwrap(),
-            coordinates[3].trim().parse().unwrap(),
-        )
-            .into(),
+        cell: parse_position(index, line, sensor)?,
+        beacon_cell: parse_position(index, line, beacon)?,
     };
-    sensor
+    Ok(sensor)
 }
 
 fn get_min_max_coord(sensors: &[Sensor]) -> (isize, isize, isize, isize) {
@@ -227,7 +170,7 @@ fn get_min_max_coord(sensors: &[Sensor]) -> (isize, isize, isize, isize) {
         .map(|sensor| sensor.cell.mdist(&sensor.beacon_cell))
         .max()
         .unwrap();
-    dbg!(mdist);
+    debug!(mdist);
     let mut max_x = sensors.iter().map(|sensor| sensor.cell.x).max().unwrap() + mdist as isize;
     let mut min_x = sensors.iter().map(|sensor| sensor.cell.x).min().unwrap() - mdist as isize;
     let mut max_y = sensors.iter().map(|sensor| sensor.cell.y).max().unwrap() + mdist as isize;
@@ -284,24 +227,56 @@ impl Wrapper {
     }
 
     fn wrap(&self, coord: Coord) -> Coord {
-        Coord::new(coord.x + self.min_x.abs(), coord.y + self.min_y.abs())
+        coord + Vector2::new(self.min_x.abs(), self.min_y.abs())
     }
 }
 
-fn run(input: Vec<String>, y_to_find: isize) -> usize {
-    let sensors: Vec<Sensor> = input
+pub const Y_TO_FIND: isize = 2000000;
+
+fn parse_sensors(input: Vec<String>) -> Result<Vec<Sensor>, ParseError> {
+    input
         .iter()
-        .map(|line| parse_line(line.to_string()))
-        .collect();
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line))
+        .collect()
+}
+
+/// Merged ranges of the cells of row `y_to_find` covered by the sensors.
+fn covered_ranges(sensors: &[Sensor], y_to_find: isize) -> Vec<(isize, isize)> {
+    let mut x_cell_ranges = sensors
+        .iter()
+        .flat_map(|sensor| {
+            let mut covered = Vec::new();
+            if let Some(cells) = sensor.get_covered_cell_y_range(y_to_find) {
+                covered.push(cells);
+            };
+
+            if sensor.cell.y == y_to_find {
+                covered.push((sensor.cell.x, sensor.cell.x));
+            }
+            if sensor.beacon_cell.y == y_to_find {
+                covered.push((sensor.beacon_cell.x, sensor.beacon_cell.x));
+            }
+            covered
+        })
+        .collect::<Vec<(isize, isize)>>();
+
+    merge_ranges(&mut x_cell_ranges)
+}
+
+#[instrument(skip_all, fields(day = 15, part = 1))]
+pub fn part1(input: Vec<String>, y_to_find: isize) -> Result<usize, ParseError> {
+    let sensors = parse_sensors(input)?;
 
     // Uncomment folowing block and comment next one then
     // run cargo test to display map
 
     // let min_max = dbg!(get_min_max_coord(&sensors));
     //
-    // let mut map = Map::new(
+    // let mut map = Grid::filled(
     //     (min_max.1 - min_max.0) as usize + 1,
     //     (min_max.3 - min_max.2) as usize + 1,
+    //     Cell::Nosignal,
     // );
     //
     // let wrapper = Wrapper::new(min_max.0, min_max.2);
@@ -323,46 +298,49 @@ fn run(input: Vec<String>, y_to_find: isize) -> usize {
     // dbg!(&map);
     // todo!();
 
-    let mut x_cell_ranges = sensors
-        .iter()
-        .flat_map(|sensor| {
-            let mut covered = Vec::new();
-            if let Some(cells) = sensor.get_covered_cell_y_range(y_to_find) {
-                covered.push(cells);
-            };
+    let x_cell_ranges = covered_ranges(&sensors, y_to_find);
 
-            if sensor.cell.y == y_to_find {
-                covered.push((sensor.cell.x, sensor.cell.x));
-            }
-            if sensor.beacon_cell.y == y_to_find {
-                covered.push((sensor.beacon_cell.x, sensor.beacon_cell.x));
-            }
-            covered
-        })
-        .collect::<Vec<(isize, isize)>>();
-
-    let x_cell_ranges = merge_ranges(&mut x_cell_ranges);
-
-    // let
-    dbg!(&x_cell_ranges);
-    if x_cell_ranges.len() == 1 {
-        (x_cell_ranges[0].1 - x_cell_ranges[0].0)
+    debug!(?x_cell_ranges);
+    match x_cell_ranges.len() {
+        // No sensor covers the row
+        0 => Ok(0),
+        1 => Ok((x_cell_ranges[0].1 - x_cell_ranges[0].0)
             .try_into()
-            .unwrap()
-    } else {
-        panic!("Non contigus")
+            .unwrap()),
+        _ => panic!("Non contigus"),
     }
 }
 
+#[instrument(skip_all, fields(day = 15, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let sensors = parse_sensors(input)?;
+
+    // The distress beacon is on the only row not covered by a single range
+    let mut y_to_find = 0;
+    let x_cell_ranges = loop {
+        let x_cell_ranges = covered_ranges(&sensors, y_to_find);
+        if x_cell_ranges.len() > 1 {
+            break x_cell_ranges;
+        }
+        y_to_find += 1;
+    };
+    debug!(?x_cell_ranges);
+    let x_to_find = x_cell_ranges[0].1 + 1;
+    Ok((x_to_find * 4000000 + y_to_find).try_into().unwrap())
+}
+
 fn merge_ranges(ranges: &mut [(isize, isize)]) -> Vec<(isize, isize)> {
     ranges.sort();
-    dbg!(&ranges);
+    trace!(?ranges);
     let mut res: Vec<(isize, isize)> = Vec::new();
+    if ranges.is_empty() {
+        return res;
+    }
     let mut start = ranges[0].0;
     let mut end = ranges[0].1;
 
     for range_index in 0..ranges.len() - 1 {
-        dbg!(start, end);
+        trace!(start, end);
         if end >= ranges[range_index + 1].0 {
             if end >= ranges[range_index + 1].1 {
                 // Included
@@ -386,12 +364,28 @@ fn merge_ranges(ranges: &mut [(isize, isize)]) -> Vec<(isize, isize)> {
     res
 }
 
-fn main() {
-    let input = parse_input(None);
+pub struct Day15;
 
-    let answer = run(input, 2000000);
+impl Solution for Day15 {
+    fn name(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
 
-    println!("Answer: {}", answer);
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input), Y_TO_FIND)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
 }
 
 #[allow(unused_imports)]
@@ -441,28 +435,39 @@ mod tests {
         assert_eq!(sensor.get_covered_cell_y_range(16), Some((8, 8)));
         assert_eq!(sensor.get_covered_cell_y_range(10), Some((2, 14)));
     }
+
     #[test]
-    fn test_run() {
-        let input = parse_input(Some(indoc!(
-            "
-            Sensor at x=2, y=18: closest beacon is at x=-2, y=15
-            Sensor at x=9, y=16: closest beacon is at x=10, y=16
-            Sensor at x=13, y=2: closest beacon is at x=15, y=3
-            Sensor at x=12, y=14: closest beacon is at x=10, y=16
-            Sensor at x=10, y=20: closest beacon is at x=10, y=16
-            Sensor at x=14, y=17: closest beacon is at x=10, y=16
-            Sensor at x=8, y=7: closest beacon is at x=2, y=10
-            Sensor at x=2, y=0: closest beacon is at x=2, y=10
-            Sensor at x=0, y=11: closest beacon is at x=2, y=10
-            Sensor at x=20, y=14: closest beacon is at x=25, y=17
-            Sensor at x=17, y=20: closest beacon is at x=21, y=22
-            Sensor at x=16, y=7: closest beacon is at x=15, y=3
-            Sensor at x=14, y=3: closest beacon is at x=15, y=3
-            Sensor at x=20, y=1: closest beacon is at x=15, y=3
-            "
-        )));
-        dbg!(&input);
-        let answer = run(input, 10);
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input, 10).unwrap();
         assert_eq!(answer, 26);
     }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 56000011);
+    }
+
+    #[test]
+    fn test_run_invalid_sensor() {
+        let input = parse_input(&EXAMPLE.replace("x=9, y=16", "x=9, y=1b"));
+        assert_eq!(
+            part1(input, 10).unwrap_err().to_string(),
+            "line 2, column 18: unexpected `1b`, expected a y coordinate"
+        );
+        let input = parse_input(&EXAMPLE.replace("x=13, y=2: closest", "x=13 y=2: closest"));
+        assert_eq!(
+            part1(input, 10).unwrap_err().to_string(),
+            "line 3, column 11: unexpected `x=13 y=2`, expected `x=<x>, y=<y>`"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day15.example();
+        assert_eq!(Day15.part1(example).unwrap().to_string(), "0");
+        assert_eq!(Day15.part2(example).unwrap().to_string(), "56000011");
+    }
 }
@@ -0,0 +1,22 @@
+//! Print the best plans minute by minute, as told in the puzzle.
+//!
+//! `cargo run -p day_16 --example plan [input]`
+
+use std::path::Path;
+
+use day_16::{parse_input, plan1, plan2, Scan, EXAMPLE};
+
+fn main() {
+    aoc_common::init_tracing(0);
+    let input_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+    let input = aoc_common::read_input(input_path, EXAMPLE).expect("Cannot read puzzle input");
+    let input = parse_input(&input);
+
+    let scan = aoc_common::unwrap_or_exit(Scan::parse(input));
+
+    let plan = aoc_common::unwrap_or_exit(plan1(&scan));
+    println!("Part 1: {} pressure released\n\n{}", plan.released(), plan);
+
+    let plan = aoc_common::unwrap_or_exit(plan2(&scan));
+    println!("Part 2: {} pressure released\n\n{}", plan.released(), plan);
+}
@@ -0,0 +1,787 @@
+//! Day 16: Proboscidea Volcanium.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use petgraph::algo::{astar, dijkstra};
+use petgraph::dot::{Config, Dot};
+use petgraph::graphmap::GraphMap;
+use petgraph::prelude::*;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    Valve BB has flow rate=13; tunnels lead to valves CC, AA
+    Valve CC has flow rate=2; tunnels lead to valves DD, BB
+    Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+    Valve EE has flow rate=3; tunnels lead to valves FF, DD
+    Valve FF has flow rate=0; tunnels lead to valves EE, GG
+    Valve GG has flow rate=0; tunnels lead to valves FF, HH
+    Valve HH has flow rate=22; tunnel leads to valve GG
+    Valve II has flow rate=0; tunnels lead to valves AA, JJ
+    Valve JJ has flow rate=21; tunnel leads to valve II
+    "
+);
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+}
+
+impl Valve {
+    fn new(name: String, flow_rate: u32) -> Self {
+        Self {
+            name,
+            flow_rate,
+            tunnels: Vec::new(),
+        }
+    }
+
+    fn add_tunnel(&mut self, name: &str) {
+        self.tunnels.push(name.to_string());
+    }
+}
+
+/// The valves of the puzzle input, with its lines to locate errors.
+pub struct Scan {
+    lines: Vec<String>,
+    valves: Vec<Valve>,
+}
+
+impl Scan {
+    pub fn parse(lines: Vec<String>) -> Result<Self, ParseError> {
+        let valves: Vec<Valve> = lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| parse_line(index, line))
+            .collect::<Result<Vec<Valve>, ParseError>>()?;
+        debug!(?valves);
+
+        // Each valve is on its own line, the tunnels last
+        for (index, (line, valve)) in lines.iter().zip(&valves).enumerate() {
+            for tunnel in &valve.tunnels {
+                if !valves.iter().any(|v| &v.name == tunnel) {
+                    let position = line.rfind(tunnel.as_str()).unwrap_or(0);
+                    let token = &line[position..position + tunnel.len()];
+                    return Err(ParseError::new(index, line, token, "a valve of the scan"));
+                }
+            }
+        }
+        Ok(Self { lines, valves })
+    }
+
+    /// Error at the end of the scan, for what is missing from it.
+    fn error(&self, expected: &str) -> ParseError {
+        let last = self.lines.len() - 1;
+        ParseError::end_of_line(last, &self.lines[last], expected)
+    }
+
+    fn graph(&self) -> GraphMap<&str, (), Directed> {
+        let mut graph: GraphMap<&str, (), Directed> = GraphMap::new();
+
+        for valve in &self.valves {
+            for tunnel in &valve.tunnels {
+                graph.add_edge(&valve.name, tunnel, ());
+            }
+        }
+
+        graph
+    }
+}
+
+/// Position, opened valves and time left of an agent.
+type StateKey = (usize, u32, u32);
+
+/// Most pressure released and the state releasing it, for each set of
+/// opened valves.
+type ByOpened = Vec<(u32, StateKey)>;
+
+/// Pressure released reaching each state, and the state before it.
+type Parents = HashMap<StateKey, (u32, StateKey)>;
+
+/// Valves with a flow rate at most, each set of opened valves having its
+/// entry in a `ByOpened` indexed by bitmask.
+const MAX_VALVES: usize = 20;
+
+/// The valves worth opening, and AA, with the time to walk between each of
+/// them. A set of opened valves is a bitmask of their indexes.
+#[derive(Debug)]
+struct Volcano {
+    start: usize,
+    names: Vec<String>,
+    flow_rates: Vec<u32>,
+    distances: Vec<Vec<Option<u32>>>,
+}
+
+impl Volcano {
+    fn new(scan: &Scan, graph: &GraphMap<&str, (), Directed>) -> Result<Self, ParseError> {
+        if scan.valves.iter().filter(|v| v.flow_rate > 0).count() > MAX_VALVES {
+            let expected = format!("at most {} valves with a flow rate", MAX_VALVES);
+            return Err(scan.error(&expected));
+        }
+        // It does not make sense to walk to valves with a 0 rate, only to go
+        // through them.
+        let valves: Vec<&Valve> = scan
+            .valves
+            .iter()
+            .filter(|v| v.flow_rate > 0 || v.name == "AA")
+            .collect();
+        let start = valves
+            .iter()
+            .position(|v| v.name == "AA")
+            .ok_or_else(|| scan.error("a valve `AA`"))?;
+        let distances = valves
+            .iter()
+            .map(|from| {
+                let dist = dijkstra(graph, from.name.as_str(), None, |_| 1);
+                valves
+                    .iter()
+                    .map(|to| dist.get(to.name.as_str()).copied())
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            start,
+            names: valves.iter().map(|v| v.name.clone()).collect(),
+            flow_rates: valves.iter().map(|v| v.flow_rate).collect(),
+            distances,
+        })
+    }
+
+    /// Most pressure released in `time` minutes for each set of opened
+    /// valves, indexed by its bitmask, with the state releasing it. The
+    /// states lead back to the start through the returned parents.
+    fn max_released(&self, time: u32) -> (ByOpened, Parents) {
+        // Best pressure released so far for each state, a state reached
+        // releasing less is not worth continuing
+        let start = (self.start, 0, time);
+        let mut best: Parents = HashMap::new();
+        let mut by_opened = vec![(0, start); 1 << self.flow_rates.len()];
+        let mut states = vec![(start, 0, start)];
+
+        while let Some((state, released, parent)) = states.pop() {
+            match best.entry(state) {
+                Entry::Occupied(entry) if entry.get().0 >= released => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((released, parent));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((released, parent));
+                }
+            }
+            let (pos, opened, time) = state;
+            if released > by_opened[opened as usize].0 {
+                by_opened[opened as usize] = (released, state);
+            }
+
+            for valve in 0..self.flow_rates.len() {
+                if opened & 1 << valve != 0 || self.flow_rates[valve] == 0 {
+                    continue;
+                }
+                // One more minute to open it, are we out of time ?
+                let Some(dist) = self.distances[pos][valve] else {
+                    continue;
+                };
+                if dist + 1 >= time {
+                    continue;
+                }
+                let remaining = time - dist - 1;
+                // The valve releases its pressure until the end
+                states.push((
+                    (valve, opened | 1 << valve, remaining),
+                    released + self.flow_rates[valve] * remaining,
+                    state,
+                ));
+            }
+        }
+        (by_opened, best)
+    }
+
+    /// Names of the valves opened on the way to `state`, in order.
+    fn openings(&self, parents: &Parents, mut state: StateKey) -> Vec<&str> {
+        let mut openings = Vec::new();
+        while state.1 != 0 {
+            openings.push(self.names[state.0].as_str());
+            state = parents[&state].1;
+        }
+        openings.reverse();
+        openings
+    }
+}
+
+/// What an agent does during a minute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Move(String),
+    Open(String),
+}
+
+/// One minute of a plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Minute {
+    pub minute: u32,
+    /// Valves opened before this minute, releasing pressure during it.
+    pub open: Vec<String>,
+    pub releasing: u32,
+    /// Pressure released since the first minute, this one included.
+    pub released: u32,
+    /// Action of each agent, `None` once it has no valve left to open.
+    pub actions: Vec<Option<Action>>,
+}
+
+/// The actions releasing the most pressure, minute by minute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Plan {
+    pub minutes: Vec<Minute>,
+}
+
+impl Plan {
+    fn new(
+        scan: &Scan,
+        graph: &GraphMap<&str, (), Directed>,
+        openings: &[Vec<&str>],
+        duration: u32,
+    ) -> Result<Self, ParseError> {
+        // Walk the shortest path to each valve of an agent then open it
+        let actions: Vec<Vec<Action>> = openings
+            .iter()
+            .map(|openings| {
+                let mut actions = Vec::new();
+                let mut pos = "AA";
+                for &valve in openings {
+                    let (_, path) = astar(graph, pos, |v| v == valve, |_| 1, |_| 0)
+                        .ok_or_else(|| scan.error(&format!("tunnels from {} to {}", pos, valve)))?;
+                    actions.extend(path[1..].iter().map(|v| Action::Move(v.to_string())));
+                    actions.push(Action::Open(valve.to_string()));
+                    pos = valve;
+                }
+                Ok(actions)
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let mut minutes = Vec::new();
+        let mut open: Vec<String> = Vec::new();
+        let mut released = 0;
+        for minute in 1..=duration {
+            let releasing = open
+                .iter()
+                .map(|name| {
+                    scan.valves
+                        .iter()
+                        .find(|v| &v.name == name)
+                        .unwrap()
+                        .flow_rate
+                })
+                .sum();
+            released += releasing;
+            let actions: Vec<Option<Action>> = actions
+                .iter()
+                .map(|actions| actions.get(minute as usize - 1).cloned())
+                .collect();
+            minutes.push(Minute {
+                minute,
+                open: open.clone(),
+                releasing,
+                released,
+                actions: actions.clone(),
+            });
+            open.extend(actions.into_iter().filter_map(|action| match action {
+                Some(Action::Open(valve)) => Some(valve),
+                _ => None,
+            }));
+            open.sort();
+        }
+
+        Ok(Self { minutes })
+    }
+
+    pub fn released(&self) -> u32 {
+        self.minutes.last().map_or(0, |minute| minute.released)
+    }
+
+    /// Tunnels taken by any agent, from one valve to the next.
+    pub fn tunnels(&self) -> HashSet<(&str, &str)> {
+        let mut tunnels = HashSet::new();
+        let mut positions = vec!["AA"; self.minutes.first().map_or(0, |m| m.actions.len())];
+        for minute in &self.minutes {
+            for (pos, action) in positions.iter_mut().zip(&minute.actions) {
+                if let Some(Action::Move(valve)) = action {
+                    tunnels.insert((*pos, valve.as_str()));
+                    *pos = valve;
+                }
+            }
+        }
+        tunnels
+    }
+
+    /// Minute each valve is opened, in order.
+    pub fn openings(&self) -> Vec<(u32, &str)> {
+        self.minutes
+            .iter()
+            .flat_map(|minute| {
+                minute.actions.iter().filter_map(|action| match action {
+                    Some(Action::Open(valve)) => Some((minute.minute, valve.as_str())),
+                    _ => None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The plan told as in the puzzle.
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, minute) in self.minutes.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "== Minute {} ==", minute.minute)?;
+            match minute.open.as_slice() {
+                [] => writeln!(f, "No valves are open.")?,
+                [valve] => writeln!(
+                    f,
+                    "Valve {} is open, releasing {} pressure.",
+                    valve, minute.releasing
+                )?,
+                [first, second] => writeln!(
+                    f,
+                    "Valves {} and {} are open, releasing {} pressure.",
+                    first, second, minute.releasing
+                )?,
+                [valves @ .., last] => writeln!(
+                    f,
+                    "Valves {}, and {} are open, releasing {} pressure.",
+                    valves.join(", "),
+                    last,
+                    minute.releasing
+                )?,
+            }
+            for (agent, action) in minute.actions.iter().enumerate() {
+                let (who, moves, opens) = match agent {
+                    0 => ("You", "move", "open"),
+                    _ => ("The elephant", "moves", "opens"),
+                };
+                match action {
+                    Some(Action::Move(valve)) => {
+                        writeln!(f, "{} {} to valve {}.", who, moves, valve)?
+                    }
+                    Some(Action::Open(valve)) => writeln!(f, "{} {} valve {}.", who, opens, valve)?,
+                    None => (),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_line(index: usize, line: &str) -> Result<Valve, ParseError> {
+    // Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+    let (name, rest) = line
+        .strip_prefix("Valve ")
+        .and_then(|line| line.split_once(" has flow rate="))
+        .ok_or_else(|| {
+            ParseError::new(
+                index,
+                line,
+                line,
+                "`Valve <name> has flow rate=<rate>; tunnels lead to valves <names>`",
+            )
+        })?;
+    let (flow_rate, tunnels) = rest
+        .split_once(';')
+        .ok_or_else(|| ParseError::end_of_line(index, line, "`;`"))?;
+    let tunnels = tunnels
+        .strip_prefix(" tunnels lead to valves ")
+        .or_else(|| tunnels.strip_prefix(" tunnel leads to valve "))
+        .ok_or_else(|| ParseError::new(index, line, tunnels.trim(), "`tunnels lead to valves`"))?;
+
+    let mut valve = Valve::new(
+        name.to_string(),
+        parse_token(index, line, flow_rate, "a flow rate")?,
+    );
+
+    for item in tunnels.split(',') {
+        valve.add_tunnel(item.trim());
+    }
+
+    Ok(valve)
+}
+
+/// Formats the tunnels between valves can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+}
+
+impl GraphFormat {
+    /// Mermaid for `.mmd` and `.mermaid` files, DOT otherwise.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("mmd" | "mermaid") => GraphFormat::Mermaid,
+            _ => GraphFormat::Dot,
+        }
+    }
+}
+
+/// The tunnels between valves, labelled with their flow rate and the minute
+/// `plan` opens them, the tunnels it takes highlighted.
+pub fn export_graph(scan: &Scan, plan: &Plan, format: GraphFormat) -> String {
+    let graph = scan.graph();
+    let tunnels = plan.tunnels();
+    let openings = plan.openings();
+
+    let label = |name: &str, line_break: &str| {
+        let flow_rate = scan
+            .valves
+            .iter()
+            .find(|v| v.name == name)
+            .map_or(0, |v| v.flow_rate);
+        let mut label = format!("{}{}flow rate {}", name, line_break, flow_rate);
+        if let Some((minute, _)) = openings.iter().find(|(_, valve)| *valve == name) {
+            label += &format!("{}open at minute {}", line_break, minute);
+        }
+        label
+    };
+    let is_open = |name: &str| openings.iter().any(|(_, valve)| *valve == name);
+
+    match format {
+        GraphFormat::Dot => format!(
+            "{:?}",
+            Dot::with_attr_getters(
+                &graph,
+                &[Config::NodeNoLabel, Config::EdgeNoLabel],
+                &|_, (from, to, _)| {
+                    if tunnels.contains(&(from, to)) {
+                        "color = red, penwidth = 3".to_string()
+                    } else {
+                        String::new()
+                    }
+                },
+                &|_, (name, _)| {
+                    let mut attributes = format!("label = \"{}\"", label(name, "\\n"));
+                    if is_open(name) {
+                        attributes += ", style = filled, fillcolor = orange";
+                    }
+                    attributes
+                },
+            )
+        ),
+        GraphFormat::Mermaid => {
+            let mut lines = vec!["flowchart LR".to_string()];
+            for name in graph.nodes() {
+                let class = if is_open(name) { ":::open" } else { "" };
+                lines.push(format!(
+                    "    {}[\"{}\"]{}",
+                    name,
+                    label(name, "<br/>"),
+                    class
+                ));
+            }
+            let mut highlighted = Vec::new();
+            for (index, (from, to, _)) in graph.all_edges().enumerate() {
+                lines.push(format!("    {} --> {}", from, to));
+                if tunnels.contains(&(from, to)) {
+                    highlighted.push(index.to_string());
+                }
+            }
+            lines.push("    classDef open fill:orange".to_string());
+            if !highlighted.is_empty() {
+                lines.push(format!(
+                    "    linkStyle {} stroke:red,stroke-width:3px",
+                    highlighted.join(",")
+                ));
+            }
+            lines.join("\n") + "\n"
+        }
+    }
+}
+
+/// Best plan opening valves alone in 30 minutes.
+pub fn plan1(scan: &Scan) -> Result<Plan, ParseError> {
+    let graph = scan.graph();
+    let volcano = Volcano::new(scan, &graph)?;
+    debug!(?volcano.flow_rates, ?volcano.distances);
+
+    let (by_opened, parents) = volcano.max_released(30);
+    let (_, state) = by_opened
+        .into_iter()
+        .max_by_key(|(released, _)| *released)
+        .unwrap();
+
+    let openings = volcano.openings(&parents, state);
+    Plan::new(scan, &graph, &[openings], 30)
+}
+
+/// Best plan opening valves with the elephant, in the 26 minutes left after
+/// teaching it.
+pub fn plan2(scan: &Scan) -> Result<Plan, ParseError> {
+    let graph = scan.graph();
+    let volcano = Volcano::new(scan, &graph)?;
+    debug!(?volcano.flow_rates, ?volcano.distances);
+
+    let (mut by_opened, parents) = volcano.max_released(26);
+
+    // Best for opening any of the valves of a set, not only all of them
+    for valve in 0..volcano.flow_rates.len() {
+        for opened in 0..by_opened.len() {
+            if opened & 1 << valve != 0 {
+                let without = by_opened[opened & !(1 << valve)];
+                if without.0 > by_opened[opened].0 {
+                    by_opened[opened] = without;
+                }
+            }
+        }
+    }
+
+    // Each one opens a different set of valves, try every way to share them
+    let all_valves = by_opened.len() - 1;
+    let mine = (0..by_opened.len())
+        .max_by_key(|&mine| by_opened[mine].0 + by_opened[all_valves & !mine].0)
+        .unwrap();
+
+    let openings: Vec<Vec<&str>> = [mine, all_valves & !mine]
+        .iter()
+        .map(|&opened| volcano.openings(&parents, by_opened[opened].1))
+        .collect();
+    Plan::new(scan, &graph, &openings, 26)
+}
+
+#[instrument(skip_all, fields(day = 16, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let plan = plan1(&Scan::parse(input)?)?;
+    let solution = plan.released();
+    debug!(solution);
+    Ok(solution as usize)
+}
+
+/// The elephant and you open valves together, after 4 minutes teaching it.
+#[instrument(skip_all, fields(day = 16, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let plan = plan2(&Scan::parse(input)?)?;
+    let solution = plan.released();
+    debug!(solution);
+    Ok(solution as usize)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn name(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 1651);
+    }
+
+    #[test]
+    fn test_volcano() {
+        let scan = Scan::parse(parse_input(EXAMPLE)).unwrap();
+        let volcano = Volcano::new(&scan, &scan.graph()).unwrap();
+        // AA, BB, CC, DD, EE, HH and JJ
+        assert_eq!(volcano.flow_rates, [0, 13, 2, 20, 3, 22, 21]);
+        assert_eq!(volcano.start, 0);
+        // AA -> DD -> EE -> FF -> GG -> HH
+        assert_eq!(volcano.distances[0][5], Some(5));
+        assert_eq!(volcano.distances[5][0], Some(5));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 1707);
+    }
+
+    #[test]
+    fn test_plan1() {
+        let plan = plan1(&Scan::parse(parse_input(EXAMPLE)).unwrap()).unwrap();
+        assert_eq!(
+            plan.openings(),
+            [
+                (2, "DD"),
+                (5, "BB"),
+                (9, "JJ"),
+                (17, "HH"),
+                (21, "EE"),
+                (24, "CC")
+            ]
+        );
+        assert_eq!(plan.minutes[20].releasing, 76);
+        assert_eq!(plan.minutes[20].released, 928);
+        assert!(plan.to_string().starts_with(indoc!(
+            "
+            == Minute 1 ==
+            No valves are open.
+            You move to valve DD.
+
+            == Minute 2 ==
+            No valves are open.
+            You open valve DD.
+
+            == Minute 3 ==
+            Valve DD is open, releasing 20 pressure.
+            You move to valve CC.
+            "
+        )));
+        assert!(plan.to_string().ends_with(indoc!(
+            "
+            == Minute 30 ==
+            Valves BB, CC, DD, EE, HH, and JJ are open, releasing 81 pressure.
+            "
+        )));
+    }
+
+    #[test]
+    fn test_plan2() {
+        let plan = plan2(&Scan::parse(parse_input(EXAMPLE)).unwrap()).unwrap();
+        assert_eq!(
+            plan.openings(),
+            [
+                (2, "DD"),
+                (3, "JJ"),
+                (7, "BB"),
+                (7, "HH"),
+                (9, "CC"),
+                (11, "EE")
+            ]
+        );
+        assert!(plan.to_string().contains(indoc!(
+            "
+            == Minute 7 ==
+            Valves DD and JJ are open, releasing 41 pressure.
+            You open valve BB.
+            The elephant opens valve HH.
+            "
+        )));
+    }
+
+    #[test]
+    fn test_export_graph() {
+        let scan = Scan::parse(parse_input(EXAMPLE)).unwrap();
+        let plan = plan1(&scan).unwrap();
+
+        let dot = export_graph(&scan, &plan, GraphFormat::Dot);
+        assert!(dot.starts_with("digraph {\n    0 [ label = \"AA\\nflow rate 0\"]\n"));
+        assert!(dot.contains(
+            "label = \"DD\\nflow rate 20\\nopen at minute 2\", style = filled, fillcolor = orange]"
+        ));
+        // AA -> DD is taken, DD -> AA is not
+        assert!(dot.contains("    0 -> 1 [ color = red, penwidth = 3]\n"));
+        assert!(dot.contains("    1 -> 0 [ ]\n"));
+
+        let mermaid = export_graph(&scan, &plan, GraphFormat::Mermaid);
+        assert!(mermaid.starts_with(indoc!(
+            "
+            flowchart LR
+                AA[\"AA<br/>flow rate 0\"]
+                DD[\"DD<br/>flow rate 20<br/>open at minute 2\"]:::open
+            "
+        )));
+        assert!(mermaid.contains("    AA --> DD\n"));
+        assert!(mermaid.ends_with(
+            "    linkStyle 0,1,4,6,7,9,10,11,12,13,14,15,16,17,18,19 stroke:red,stroke-width:3px\n"
+        ));
+    }
+
+    #[test]
+    fn test_graph_format() {
+        assert_eq!(
+            GraphFormat::from_path(Path::new("graph.dot")),
+            GraphFormat::Dot
+        );
+        assert_eq!(
+            GraphFormat::from_path(Path::new("graph.mmd")),
+            GraphFormat::Mermaid
+        );
+        assert_eq!(GraphFormat::from_path(Path::new("graph")), GraphFormat::Dot);
+    }
+
+    #[test]
+    fn test_run_invalid_valve() {
+        let input = parse_input(&EXAMPLE.replace("rate=13", "rate=x"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 24: unexpected `x`, expected a flow rate"
+        );
+        let input = parse_input(&EXAMPLE.replace("tunnel leads to valve GG", "tunnel to GG"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 8, column 28: unexpected `tunnel to GG`, expected `tunnels lead to valves`"
+        );
+        let input = parse_input(&EXAMPLE.replace("AA", "AB"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 10, column 52: unexpected end of line, expected a valve `AA`"
+        );
+        let input = parse_input(&EXAMPLE.replace("valves DD, II, BB", "valves DD, II, BB, ZZ"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 62: unexpected `ZZ`, expected a valve of the scan"
+        );
+    }
+
+    #[test]
+    fn test_run_too_many_valves() {
+        let mut input = parse_input(EXAMPLE);
+        for index in 0..MAX_VALVES {
+            input.push(format!(
+                "Valve V{} has flow rate=1; tunnel leads to valve AA",
+                index
+            ));
+        }
+        assert_eq!(
+            part1(input).unwrap_err().expected,
+            "at most 20 valves with a flow rate"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day16.example();
+        assert_eq!(Day16.part1(example).unwrap().to_string(), "1651");
+        assert_eq!(Day16.part2(example).unwrap().to_string(), "1707");
+    }
+}
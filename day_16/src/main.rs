@@ -0,0 +1,50 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use day_16::{export_graph, parse_input, part1, part2, plan1, GraphFormat, Scan, EXAMPLE};
+
+/// `day_16 [--dot <path>] [input]`, `--dot` writing the tunnels and the best
+/// path of part 1 as DOT, or as a Mermaid flowchart for a `.mmd` path.
+fn parse_args() -> (Option<PathBuf>, Option<PathBuf>) {
+    let mut args = env::args_os().skip(1);
+    let (mut dot, mut input) = (None, None);
+    while let Some(arg) = args.next() {
+        if arg == "--dot" {
+            let Some(path) = args.next() else {
+                eprintln!("--dot needs a path");
+                process::exit(1);
+            };
+            dot = Some(PathBuf::from(path));
+        } else {
+            input = Some(PathBuf::from(arg));
+        }
+    }
+    (dot, input)
+}
+
+fn main() {
+    aoc_common::init_tracing(0);
+    let (dot, input_arg) = parse_args();
+    let input_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+    let input = aoc_common::read_input_from(input_arg, input_path, EXAMPLE)
+        .expect("Cannot read puzzle input");
+    let input = parse_input(&input);
+
+    let answer = aoc_common::unwrap_or_exit(part1(input.clone()));
+    println!("Part 1: {}", answer);
+
+    let answer = aoc_common::unwrap_or_exit(part2(input.clone()));
+    println!("Part 2: {}", answer);
+
+    if let Some(path) = dot {
+        let scan = aoc_common::unwrap_or_exit(Scan::parse(input));
+        let plan = aoc_common::unwrap_or_exit(plan1(&scan));
+        let graph = export_graph(&scan, &plan, GraphFormat::from_path(&path));
+        if let Err(e) = fs::write(&path, graph) {
+            eprintln!("Cannot write {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
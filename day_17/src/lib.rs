@@ -0,0 +1,354 @@
+//! Day 17: Pyroclastic Flow.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, Direction, Grid, ParseError, Solution, SolveError, Vector2};
+use core::fmt;
+use indoc::indoc;
+use std::collections::HashMap;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    >>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>
+    "
+);
+
+const CHAMBER_WIDTH: usize = 7;
+/// Rows of the chamber profile compared to find a cycle, deeper rows being
+/// out of reach of the next rocks.
+const PROFILE_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Cell {
+    Air,
+    Rock,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Rock => '#',
+            Cell::Air => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Jet {
+    Left,
+    Right,
+}
+
+/// The cells of a falling rock. As in the map, y grows downward.
+#[derive(Debug, Clone)]
+struct Rock {
+    cells: Vec<Coord>,
+}
+
+impl Rock {
+    /// The `index` rock of the repeating shapes, its top left corner at `origin`.
+    fn new(index: usize, origin: Coord) -> Self {
+        let shape: &[(isize, isize)] = match index % 5 {
+            0 => &[(0, 0), (1, 0), (2, 0), (3, 0)],
+            1 => &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+            2 => &[(2, 0), (2, 1), (0, 2), (1, 2), (2, 2)],
+            3 => &[(0, 0), (0, 1), (0, 2), (0, 3)],
+            _ => &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        };
+        Self {
+            cells: shape
+                .iter()
+                .map(|offset| origin + Vector2::from(*offset))
+                .collect(),
+        }
+    }
+
+    fn height(&self) -> isize {
+        let top = self.cells.iter().map(|c| c.y).min().unwrap();
+        let bottom = self.cells.iter().map(|c| c.y).max().unwrap();
+        bottom - top + 1
+    }
+
+    fn move_down(&mut self) {
+        self.cells.iter_mut().for_each(|c| c.move_down());
+    }
+
+    fn move_left(&mut self) {
+        self.cells.iter_mut().for_each(|c| c.move_left());
+    }
+
+    fn move_right(&mut self) {
+        self.cells.iter_mut().for_each(|c| c.move_right());
+    }
+}
+
+trait Fall {
+    fn check_down(&self, map: &Grid<Cell>) -> bool;
+    fn check_left(&self, map: &Grid<Cell>) -> bool;
+    fn check_right(&self, map: &Grid<Cell>) -> bool;
+}
+
+/// Whether `coord` is free, the walls and the floor being out of the map.
+fn is_air(map: &Grid<Cell>, coord: Coord) -> bool {
+    match map.get_cell(coord) {
+        None => false,
+        Some(cell) => match cell {
+            Cell::Rock => false,
+            Cell::Air => true,
+        },
+    }
+}
+
+impl Fall for Rock {
+    fn check_down(&self, map: &Grid<Cell>) -> bool {
+        self.cells
+            .iter()
+            .all(|c| is_air(map, *c + Direction::Down.vector()))
+    }
+
+    fn check_left(&self, map: &Grid<Cell>) -> bool {
+        self.cells
+            .iter()
+            .all(|c| is_air(map, *c + Direction::Left.vector()))
+    }
+
+    fn check_right(&self, map: &Grid<Cell>) -> bool {
+        self.cells
+            .iter()
+            .all(|c| is_air(map, *c + Direction::Right.vector()))
+    }
+}
+
+fn parse_jets(input: &[String]) -> Result<Vec<Jet>, ParseError> {
+    let mut jets = Vec::new();
+    for (index, line) in input.iter().enumerate() {
+        for (position, c) in line.char_indices() {
+            jets.push(match c {
+                '<' => Jet::Left,
+                '>' => Jet::Right,
+                _ => {
+                    let token = &line[position..position + c.len_utf8()];
+                    return Err(ParseError::new(index, line, token, "`<` or `>`"));
+                }
+            });
+        }
+    }
+    if jets.is_empty() {
+        return Err(ParseError::end_of_line(0, "", "`<` or `>`"));
+    }
+    Ok(jets)
+}
+
+/// A chamber where rocks fall one after the other, its floor just below the
+/// last row of the map.
+struct Chamber {
+    map: Grid<Cell>,
+    jets: Vec<Jet>,
+    next_jet: usize,
+    nb_rocks: usize,
+    /// Row of the highest rock, the floor when there is no rock yet.
+    top: isize,
+}
+
+impl Chamber {
+    /// A chamber high enough for `max_rocks` rocks.
+    fn new(jets: Vec<Jet>, max_rocks: usize) -> Self {
+        let height = max_rocks * 4 + 8;
+        Self {
+            map: Grid::filled(CHAMBER_WIDTH, height, Cell::Air),
+            jets,
+            next_jet: 0,
+            nb_rocks: 0,
+            top: height as isize,
+        }
+    }
+
+    fn height(&self) -> usize {
+        (self.map.height as isize - self.top) as usize
+    }
+
+    fn drop_rock(&mut self) {
+        // Two units from the left wall, its bottom three units above the top
+        let mut rock = Rock::new(self.nb_rocks, (2, 0).into());
+        let origin_y = self.top - 3 - rock.height();
+        rock.cells.iter_mut().for_each(|c| c.y += origin_y);
+
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            match jet {
+                Jet::Left if rock.check_left(&self.map) => rock.move_left(),
+                Jet::Right if rock.check_right(&self.map) => rock.move_right(),
+                _ => (),
+            }
+            if !rock.check_down(&self.map) {
+                break;
+            }
+            rock.move_down();
+        }
+
+        trace!(?rock);
+        for cell in &rock.cells {
+            *self.map.get_mut_cell(*cell).unwrap() = Cell::Rock;
+            self.top = self.top.min(cell.y);
+        }
+        self.nb_rocks += 1;
+    }
+
+    /// What the next rocks can land on: the next shape, the next jet and the
+    /// top rows of the chamber.
+    fn state(&self) -> (usize, usize, Vec<bool>) {
+        let profile = (self.top..self.top + PROFILE_DEPTH as isize)
+            .flat_map(|y| (0..CHAMBER_WIDTH as isize).map(move |x| (x, y)))
+            .map(|(x, y)| !is_air(&self.map, (x, y).into()))
+            .collect();
+        (self.nb_rocks % 5, self.next_jet, profile)
+    }
+}
+
+/// Height of the tower once `nb_rocks` rocks have fallen. A long fall
+/// repeats itself, so the tower height is extrapolated from the first cycle.
+fn tower_height(input: Vec<String>, nb_rocks: usize) -> Result<usize, ParseError> {
+    let jets = parse_jets(&input)?;
+    // The shapes and the jets are back to the same state within this many rocks
+    let max_rocks = nb_rocks.min(10 * jets.len() + PROFILE_DEPTH * 10);
+    let mut chamber = Chamber::new(jets, max_rocks);
+
+    let mut seen: HashMap<(usize, usize, Vec<bool>), usize> = HashMap::new();
+    let mut heights = vec![0];
+    while chamber.nb_rocks < nb_rocks {
+        if let Some(start) = seen.insert(chamber.state(), chamber.nb_rocks) {
+            let period = chamber.nb_rocks - start;
+            let growth = chamber.height() - heights[start];
+            let (cycles, rest) = (
+                (nb_rocks - chamber.nb_rocks) / period,
+                (nb_rocks - chamber.nb_rocks) % period,
+            );
+            debug!(start, period, growth);
+            return Ok(chamber.height() + cycles * growth + heights[start + rest] - heights[start]);
+        }
+        if chamber.nb_rocks == max_rocks {
+            let last = input.len() - 1;
+            let expected = format!("jets repeating within {} rocks", max_rocks);
+            return Err(ParseError::end_of_line(last, &input[last], &expected));
+        }
+        chamber.drop_rock();
+        heights.push(chamber.height());
+    }
+    debug!(?chamber.map);
+
+    Ok(chamber.height())
+}
+
+#[instrument(skip_all, fields(day = 17, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    tower_height(input, 2022)
+}
+
+#[instrument(skip_all, fields(day = 17, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    tower_height(input, 1_000_000_000_000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn name(&self) -> &'static str {
+        "Pyroclastic Flow"
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_drop_rock() {
+        let jets = parse_jets(&parse_input(EXAMPLE)).unwrap();
+        let mut chamber = Chamber::new(jets, 3);
+        for _ in 0..3 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height(), 6);
+        assert_eq!(
+            format!("{:?}", chamber.map)
+                .lines()
+                .skip(15)
+                .collect::<Vec<&str>>(),
+            [
+                "..#....", //
+                "..#....", "####...", "..###..", "...#...", "..####.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 3068);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 1514285714288);
+    }
+
+    #[test]
+    fn test_tower_height() {
+        // Without and with the cycle detection
+        for nb_rocks in [10, 100, 1000] {
+            let input = parse_input(EXAMPLE);
+            let jets = parse_jets(&input).unwrap();
+            let mut chamber = Chamber::new(jets, nb_rocks);
+            for _ in 0..nb_rocks {
+                chamber.drop_rock();
+            }
+            assert_eq!(tower_height(input, nb_rocks).unwrap(), chamber.height());
+        }
+    }
+
+    #[test]
+    fn test_run_invalid_jet() {
+        let input = parse_input(&EXAMPLE.replace(">>><<><", ">>><v><"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 5: unexpected `v`, expected `<` or `>`"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day17.example();
+        assert_eq!(Day17.part1(example).unwrap().to_string(), "3068");
+        assert_eq!(Day17.part2(example).unwrap().to_string(), "1514285714288");
+    }
+}
@@ -0,0 +1,182 @@
+//! Day 18: Boiling Boulders.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, Coord3, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::collections::{HashSet, VecDeque};
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    2,2,2
+    1,2,2
+    3,2,2
+    2,1,2
+    2,3,2
+    2,2,1
+    2,2,3
+    2,2,4
+    2,2,6
+    1,2,5
+    3,2,5
+    2,1,5
+    2,3,5
+    "
+);
+
+fn parse_line(index: usize, line: &str) -> Result<Coord3, ParseError> {
+    let mut coords = line.split(',');
+    let mut next = |expected: &str| match coords.next() {
+        Some(token) => parse_token(index, line, token, expected),
+        None => Err(ParseError::end_of_line(index, line, expected)),
+    };
+    let cube = Coord3::new(
+        next("a x coordinate")?,
+        next("a y coordinate")?,
+        next("a z coordinate")?,
+    );
+    if let Some(token) = coords.next() {
+        return Err(ParseError::new(index, line, token, "the end of the line"));
+    }
+    Ok(cube)
+}
+
+fn parse_cubes(input: Vec<String>) -> Result<HashSet<Coord3>, ParseError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_line(index, line))
+        .collect()
+}
+
+#[instrument(skip_all, fields(day = 18, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let cubes = parse_cubes(input)?;
+
+    // Faces not covered by another cube
+    let surface = cubes
+        .iter()
+        .flat_map(|cube| cube.neighbours())
+        .filter(|neighbour| !cubes.contains(neighbour))
+        .count();
+    debug!(surface);
+    Ok(surface)
+}
+
+#[instrument(skip_all, fields(day = 18, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let cubes = parse_cubes(input)?;
+
+    // A box one unit larger than the droplet, so the steam goes all around it
+    let min = Coord3::new(
+        cubes.iter().map(|c| c.x).min().unwrap() - 1,
+        cubes.iter().map(|c| c.y).min().unwrap() - 1,
+        cubes.iter().map(|c| c.z).min().unwrap() - 1,
+    );
+    let max = Coord3::new(
+        cubes.iter().map(|c| c.x).max().unwrap() + 1,
+        cubes.iter().map(|c| c.y).max().unwrap() + 1,
+        cubes.iter().map(|c| c.z).max().unwrap() + 1,
+    );
+    let in_box = |c: &Coord3| {
+        (min.x..=max.x).contains(&c.x)
+            && (min.y..=max.y).contains(&c.y)
+            && (min.z..=max.z).contains(&c.z)
+    };
+
+    // Flood the box with steam from a corner, counting the faces it touches
+    let mut steam: HashSet<Coord3> = HashSet::from([min]);
+    let mut queue: VecDeque<Coord3> = VecDeque::from([min]);
+    let mut surface = 0;
+    while let Some(air) = queue.pop_front() {
+        for neighbour in air.neighbours() {
+            if cubes.contains(&neighbour) {
+                surface += 1;
+            } else if in_box(&neighbour) && steam.insert(neighbour) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    debug!(surface);
+    Ok(surface)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn name(&self) -> &'static str {
+        "Boiling Boulders"
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_two_cubes() {
+        let input = parse_input("1,1,1\n2,1,1\n");
+        assert_eq!(part1(input.clone()).unwrap(), 10);
+        assert_eq!(part2(input).unwrap(), 10);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 64);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 58);
+    }
+
+    #[test]
+    fn test_run_invalid_cube() {
+        let input = parse_input(&EXAMPLE.replace("2,1,5", "2,a,5"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 12, column 3: unexpected `a`, expected a y coordinate"
+        );
+        let input = parse_input(&EXAMPLE.replace("2,2,6", "2,2"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 9, column 4: unexpected end of line, expected a z coordinate"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day18.example();
+        assert_eq!(Day18.part1(example).unwrap().to_string(), "64");
+        assert_eq!(Day18.part2(example).unwrap().to_string(), "58");
+    }
+}
@@ -0,0 +1,346 @@
+//! Day 19: Not Enough Minerals.
+
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{multispace0, multispace1},
+    combinator::{cut, opt},
+    error::{context, ErrorKind, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded},
+    *,
+};
+use rayon::prelude::*;
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+    Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+    "
+);
+
+type Res<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, PartialEq)]
+struct Blueprint {
+    id: u32,
+    /// Ore, clay and obsidian needed by each robot, indexed by what it collects.
+    costs: [[u32; 3]; 4],
+}
+
+fn parse_robot<'a>(input: &'a str, robot: &'static str) -> Res<'a, [u32; 3]> {
+    let (input, _) = multispace1(input)?;
+    let (input, _) = context(robot, tag(robot))(input)?;
+    let (input, ore) = context("an ore cost", nom::character::complete::u32)(input)?;
+    let (input, _) = context("`ore`", tag(" ore"))(input)?;
+    let (input, other) = opt(preceded(
+        tag(" and "),
+        cut(pair(
+            context("a cost", nom::character::complete::u32),
+            preceded(
+                tag(" "),
+                context("`clay` or `obsidian`", alt((tag("clay"), tag("obsidian")))),
+            ),
+        )),
+    ))(input)?;
+    let (input, _) = context("`.`", tag("."))(input)?;
+
+    let mut cost = [ore, 0, 0];
+    match other {
+        Some((clay, "clay")) => cost[CLAY] = clay,
+        Some((obsidian, _)) => cost[OBSIDIAN] = obsidian,
+        None => (),
+    }
+    Ok((input, cost))
+}
+
+fn parse_blueprint(input: &str) -> Res<'_, Blueprint> {
+    let (input, id) = delimited(
+        context("`Blueprint`", tag("Blueprint ")),
+        context("a blueprint id", nom::character::complete::u32),
+        context("`:`", tag(":")),
+    )(input)?;
+    let (input, ore) = parse_robot(input, "Each ore robot costs ")?;
+    let (input, clay) = parse_robot(input, "Each clay robot costs ")?;
+    let (input, obsidian) = parse_robot(input, "Each obsidian robot costs ")?;
+    let (input, geode) = parse_robot(input, "Each geode robot costs ")?;
+    Ok((
+        input,
+        Blueprint {
+            id,
+            costs: [ore, clay, obsidian, geode],
+        },
+    ))
+}
+
+/// Locate a nom error within the whole `input`.
+fn to_parse_error(input: &str, e: Err<VerboseError<&str>>) -> ParseError {
+    let e = match e {
+        Err::Error(e) | Err::Failure(e) => e,
+        Err::Incomplete(_) => return ParseError::at_offset(input, input.len(), "a blueprint"),
+    };
+    let rest = e.errors.first().map_or("", |(rest, _)| rest);
+    let expected = e
+        .errors
+        .iter()
+        .find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(*context),
+            VerboseErrorKind::Nom(ErrorKind::MultiSpace) => Some("a space or a line break"),
+            _ => None,
+        })
+        .unwrap_or("a blueprint");
+    ParseError::at_offset(input, input.len() - rest.len(), expected)
+}
+
+/// Blueprints, one per line or wrapped over several lines as in the puzzle.
+fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let input = input.trim_end();
+    let (rest, blueprints) = preceded(
+        multispace0,
+        separated_list1(multispace1, cut(parse_blueprint)),
+    )(input)
+    .map_err(|e| to_parse_error(input, e))?;
+    if !rest.is_empty() {
+        return Err(ParseError::at_offset(
+            input,
+            input.len() - rest.len(),
+            "a line break between blueprints",
+        ));
+    }
+    Ok(blueprints)
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    time_left: u32,
+    robots: [u32; 4],
+    resources: [u32; 4],
+}
+
+impl State {
+    fn new(duration: u32) -> Self {
+        Self {
+            time_left: duration,
+            robots: [1, 0, 0, 0],
+            resources: [0; 4],
+        }
+    }
+
+    fn next(&mut self, minutes: u32) {
+        for (resource, robots) in self.resources.iter_mut().zip(self.robots) {
+            *resource += robots * minutes;
+        }
+        self.time_left -= minutes;
+    }
+
+    /// Minutes to collect what `cost` needs, `None` when one of its
+    /// resources is not collected at all.
+    fn wait_for(&self, cost: &[u32; 3]) -> Option<u32> {
+        cost.iter()
+            .enumerate()
+            .map(|(resource, &cost)| {
+                if self.resources[resource] >= cost {
+                    Some(0)
+                } else if self.robots[resource] == 0 {
+                    None
+                } else {
+                    Some((cost - self.resources[resource]).div_ceil(self.robots[resource]))
+                }
+            })
+            .try_fold(0, |wait, minutes| minutes.map(|minutes| wait.max(minutes)))
+    }
+
+    /// The state once the next `robot` is built, `None` if it would not be
+    /// ready before the end.
+    fn build(&self, robot: usize, cost: &[u32; 3]) -> Option<State> {
+        let wait = self.wait_for(cost)?;
+        if wait + 1 >= self.time_left {
+            return None;
+        }
+        let mut state = self.clone();
+        // One more minute to build it
+        state.next(wait + 1);
+        for (resource, cost) in state.resources.iter_mut().zip(cost) {
+            *resource -= cost;
+        }
+        state.robots[robot] += 1;
+        Some(state)
+    }
+
+    /// Geodes opened at the end if no more robot is built.
+    fn geodes(&self) -> u32 {
+        self.resources[GEODE] + self.robots[GEODE] * self.time_left
+    }
+
+    /// Geodes opened at the end if a geode robot was built every minute.
+    fn max_geodes(&self) -> u32 {
+        self.geodes() + self.time_left * self.time_left.saturating_sub(1) / 2
+    }
+}
+
+/// Most geodes opened in `duration` minutes, choosing the next robot to
+/// build depth first.
+fn max_geodes(blueprint: &Blueprint, duration: u32) -> u32 {
+    // A factory builds a robot a minute, more robots than the most of a
+    // resource a robot needs would collect more than it can spend
+    let mut max_robots = [u32::MAX; 4];
+    for resource in [ORE, CLAY, OBSIDIAN] {
+        max_robots[resource] = blueprint.costs.iter().map(|c| c[resource]).max().unwrap();
+    }
+
+    let mut solution = 0;
+    let mut simulations = vec![State::new(duration)];
+    while let Some(state) = simulations.pop() {
+        solution = solution.max(state.geodes());
+        if state.max_geodes() <= solution {
+            continue;
+        }
+        // Geode robots last, to be tried first
+        for robot in [ORE, CLAY, OBSIDIAN, GEODE] {
+            if state.robots[robot] >= max_robots[robot] {
+                continue;
+            }
+            if let Some(new_state) = state.build(robot, &blueprint.costs[robot]) {
+                simulations.push(new_state);
+            }
+        }
+    }
+    debug!(blueprint.id, solution);
+    solution
+}
+
+#[instrument(skip_all, fields(day = 19, part = 1))]
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let blueprints = parse_blueprints(input)?;
+    Ok(blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.id * max_geodes(blueprint, 24))
+        .sum())
+}
+
+/// The elephants ate most of the blueprints.
+#[instrument(skip_all, fields(day = 19, part = 2))]
+pub fn part2(input: &str) -> Result<u32, ParseError> {
+    let blueprints = parse_blueprints(input)?;
+    Ok(blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| max_geodes(blueprint, 32))
+        .product())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn name(&self) -> &'static str {
+        "Not Enough Minerals"
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(input)?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(input)?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_blueprints() {
+        let blueprints = parse_blueprints(EXAMPLE).unwrap();
+        assert_eq!(
+            blueprints[1],
+            Blueprint {
+                id: 2,
+                costs: [[2, 0, 0], [3, 0, 0], [3, 8, 0], [3, 0, 12]],
+            }
+        );
+
+        // As wrapped in the puzzle text
+        let wrapped = indoc!(
+            "
+            Blueprint 1:
+              Each ore robot costs 4 ore.
+              Each clay robot costs 2 ore.
+              Each obsidian robot costs 3 ore and 14 clay.
+              Each geode robot costs 2 ore and 7 obsidian.
+
+            Blueprint 2:
+              Each ore robot costs 2 ore.
+              Each clay robot costs 3 ore.
+              Each obsidian robot costs 3 ore and 8 clay.
+              Each geode robot costs 3 ore and 12 obsidian.
+            "
+        );
+        assert_eq!(parse_blueprints(wrapped).unwrap(), blueprints);
+    }
+
+    #[test]
+    fn test_max_geodes() {
+        let blueprints = parse_blueprints(EXAMPLE).unwrap();
+        assert_eq!(max_geodes(&blueprints[0], 24), 9);
+        assert_eq!(max_geodes(&blueprints[1], 24), 12);
+    }
+
+    #[test]
+    fn test_part1() {
+        let answer = part1(EXAMPLE).unwrap();
+        assert_eq!(answer, 33);
+    }
+
+    #[test]
+    fn test_part2() {
+        let answer = part2(EXAMPLE).unwrap();
+        assert_eq!(answer, 56 * 62);
+    }
+
+    #[test]
+    fn test_run_invalid_blueprint() {
+        let input = EXAMPLE.replace("costs 3 ore and 8 clay", "costs 3 ore and 8 sand");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 2, column 109: unexpected `sand.`, expected `clay` or `obsidian`"
+        );
+        let input = EXAMPLE.replace("Blueprint 2:", "Blueprint two:");
+        assert_eq!(
+            part1(&input).unwrap_err().to_string(),
+            "line 2, column 11: unexpected `two:`, expected a blueprint id"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day19.example();
+        assert_eq!(Day19.part1(example).unwrap().to_string(), "33");
+        assert_eq!(Day19.part2(example).unwrap().to_string(), "3472");
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 20: Grove Positioning System.
+
+pub use aoc_common::parse_input;
+use aoc_common::{parse_token, Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    1
+    2
+    -3
+    3
+    -2
+    0
+    4
+    "
+);
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// The numbers of the file in a circle, linked by their index in the
+/// original order, which is also the order they move in.
+#[derive(Debug)]
+struct Ring {
+    values: Vec<i64>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+}
+
+impl Ring {
+    fn new(values: Vec<i64>) -> Self {
+        let len = values.len();
+        Self {
+            next: (0..len).map(|i| (i + 1) % len).collect(),
+            prev: (0..len).map(|i| (i + len - 1) % len).collect(),
+            values,
+        }
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.prev[index], self.next[index]);
+        self.next[prev] = next;
+        self.prev[next] = prev;
+    }
+
+    fn insert_after(&mut self, index: usize, after: usize) {
+        let next = self.next[after];
+        self.prev[index] = after;
+        self.next[index] = next;
+        self.next[after] = index;
+        self.prev[next] = index;
+    }
+
+    /// Move each number as many positions as its value, in the original order.
+    fn mix(&mut self) {
+        // Out of the circle, a number moves among the others only
+        let others = self.values.len() - 1;
+        if others == 0 {
+            return;
+        }
+        for index in 0..self.values.len() {
+            let shift = self.values[index].rem_euclid(others as i64) as usize;
+            if shift == 0 {
+                continue;
+            }
+            let mut after = self.prev[index];
+            self.unlink(index);
+            // Going forward `shift` numbers is going back `others - shift`
+            if shift <= others / 2 {
+                for _ in 0..shift {
+                    after = self.next[after];
+                }
+            } else {
+                for _ in 0..others - shift {
+                    after = self.prev[after];
+                }
+            }
+            self.insert_after(index, after);
+        }
+    }
+
+    /// The numbers in the circle, starting from `start`.
+    fn iter_from(&self, start: usize) -> impl Iterator<Item = i64> + '_ {
+        let mut index = start;
+        (0..self.values.len()).map(move |_| {
+            let value = self.values[index];
+            index = self.next[index];
+            value
+        })
+    }
+}
+
+fn parse_numbers(input: Vec<String>) -> Result<Vec<i64>, ParseError> {
+    let numbers = input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_token(index, line, line, "a number"))
+        .collect::<Result<Vec<i64>, ParseError>>()?;
+    if !numbers.contains(&0) {
+        let last = input.len() - 1;
+        return Err(ParseError::end_of_line(
+            last,
+            &input[last],
+            "a 0 in the file",
+        ));
+    }
+    Ok(numbers)
+}
+
+/// Sum of the 1000th, 2000th and 3000th numbers after 0 once mixed.
+fn decrypt(input: Vec<String>, key: i64, rounds: usize) -> Result<i64, ParseError> {
+    let numbers = parse_numbers(input)?;
+    let mut ring = Ring::new(numbers.iter().map(|n| n * key).collect());
+    for round in 0..rounds {
+        ring.mix();
+        trace!(round, ?ring);
+    }
+
+    let zero = numbers.iter().position(|&n| n == 0).unwrap();
+    let mixed: Vec<i64> = ring.iter_from(zero).collect();
+    let coordinates: Vec<i64> = [1000, 2000, 3000]
+        .iter()
+        .map(|nth| mixed[nth % mixed.len()])
+        .collect();
+    debug!(?coordinates);
+
+    Ok(coordinates.iter().sum())
+}
+
+#[instrument(skip_all, fields(day = 20, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<i64, ParseError> {
+    decrypt(input, 1, 1)
+}
+
+#[instrument(skip_all, fields(day = 20, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<i64, ParseError> {
+    decrypt(input, DECRYPTION_KEY, 10)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn name(&self) -> &'static str {
+        "Grove Positioning System"
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_mix() {
+        let mut ring = Ring::new(vec![1, 2, -3, 3, -2, 0, 4]);
+        ring.mix();
+        assert_eq!(
+            ring.iter_from(0).collect::<Vec<i64>>(),
+            [1, 2, -3, 4, 0, 3, -2]
+        );
+
+        // Moving a full turn among the others ends where it started
+        let mut ring = Ring::new(vec![3, 0, 3, 1]);
+        ring.mix();
+        assert_eq!(ring.iter_from(1).collect::<Vec<i64>>(), [0, 3, 3, 1]);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 1623178306);
+    }
+
+    #[test]
+    fn test_run_invalid_number() {
+        let input = parse_input(&EXAMPLE.replace("-3", "-x"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 1: unexpected `-x`, expected a number"
+        );
+        let input = parse_input(&EXAMPLE.replace('0', "7"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 7, column 2: unexpected end of line, expected a 0 in the file"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day20.example();
+        assert_eq!(Day20.part1(example).unwrap().to_string(), "3");
+        assert_eq!(Day20.part2(example).unwrap().to_string(), "1623178306");
+    }
+}
@@ -0,0 +1,523 @@
+//! Day 21: Monkey Math.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, ParseError, Solution, SolveError};
+use indoc::indoc;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, one_of, space1},
+    combinator::eof,
+    error::{context, VerboseError, VerboseErrorKind},
+    sequence::{delimited, terminated},
+    *,
+};
+use std::collections::HashMap;
+use std::fmt;
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    root: pppw + sjmn
+    dbpl: 5
+    cczh: sllz + lgvd
+    zczc: 2
+    ptdq: humn - dvpt
+    dvpt: 3
+    lfqf: 4
+    humn: 5
+    ljgn: 2
+    sjmn: drzm * dbpl
+    sllz: 4
+    pppw: cczh / lfqf
+    lgvd: ljgn * ptdq
+    drzm: hmdt - zczc
+    hmdt: 32
+    "
+);
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+type Res<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Monkey(String),
+    N(i64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Monkey(name) => write!(f, "{}", name),
+            Value::N(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operation {
+    Yell(Value),
+    Add((Value, Value)),
+    Sub((Value, Value)),
+    Mul((Value, Value)),
+    Div((Value, Value)),
+}
+
+impl Operation {
+    fn operands(&self) -> Vec<&Value> {
+        match self {
+            Operation::Yell(value) => vec![value],
+            Operation::Add((a, b))
+            | Operation::Sub((a, b))
+            | Operation::Mul((a, b))
+            | Operation::Div((a, b)) => vec![a, b],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    /// Line of the monkey in the input, to locate errors.
+    index: usize,
+    operation: Operation,
+}
+
+fn parse_name(input: &str) -> Res<'_, Value> {
+    context("a monkey name", alpha1)
+        .map(|name: &str| Value::Monkey(name.to_string()))
+        .parse(input)
+}
+
+fn parse_operation(input: &str) -> Res<'_, Operation> {
+    let number = nom::character::complete::i64.map(|n| Operation::Yell(Value::N(n)));
+    let operation = |input| {
+        let (input, a) = parse_name(input)?;
+        let (input, op) = delimited(
+            space1,
+            context("`+`, `-`, `*` or `/`", one_of("+-*/")),
+            space1,
+        )(input)?;
+        let (input, b) = parse_name(input)?;
+        let operation = match op {
+            '+' => Operation::Add((a, b)),
+            '-' => Operation::Sub((a, b)),
+            '*' => Operation::Mul((a, b)),
+            '/' => Operation::Div((a, b)),
+            _ => unreachable!(),
+        };
+        Ok((input, operation))
+    };
+    context("a number or an operation", alt((number, operation)))(input)
+}
+
+fn parse_monkey(input: &str) -> Res<'_, (&str, Operation)> {
+    let (input, name) = context("a monkey name", alpha1)(input)?;
+    let (input, _) = context("`: `", tag(": "))(input)?;
+    let (input, operation) =
+        terminated(parse_operation, context("the end of the line", eof))(input)?;
+    Ok((input, (name, operation)))
+}
+
+/// Locate a nom error within the `index` line of the input.
+fn to_parse_error(index: usize, line: &str, e: Err<VerboseError<&str>>) -> ParseError {
+    let e = match e {
+        Err::Error(e) | Err::Failure(e) => e,
+        Err::Incomplete(_) => return ParseError::end_of_line(index, line, "a monkey"),
+    };
+    let rest = e.errors.first().map_or("", |(rest, _)| rest);
+    let expected = e
+        .errors
+        .iter()
+        .rev()
+        .find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        })
+        .unwrap_or("a monkey");
+    match rest.split_whitespace().next() {
+        Some(token) => ParseError::new(index, line, token, expected),
+        None => ParseError::end_of_line(index, line, expected),
+    }
+}
+
+/// The monkeys by name, each waiting for the numbers of the others it needs.
+struct Troop<'a> {
+    lines: &'a [String],
+    monkeys: HashMap<&'a str, Monkey>,
+}
+
+impl<'a> Troop<'a> {
+    fn parse(lines: &'a [String]) -> Result<Self, ParseError> {
+        let mut monkeys = HashMap::new();
+        for (index, line) in lines.iter().enumerate() {
+            let (_, (name, operation)) =
+                parse_monkey(line).map_err(|e| to_parse_error(index, line, e))?;
+            monkeys.insert(name, Monkey { index, operation });
+        }
+        Ok(Self { lines, monkeys })
+    }
+
+    /// Error on the `name` operand of `monkey`.
+    fn error(&self, monkey: &Monkey, name: &str, expected: &str) -> ParseError {
+        let line = &self.lines[monkey.index];
+        let (_, operation) = line.split_once(": ").unwrap();
+        let token = operation
+            .split_whitespace()
+            .find(|token| *token == name)
+            .unwrap_or(operation);
+        ParseError::new(monkey.index, line, token, expected)
+    }
+
+    fn get(&self, name: &str) -> Result<&Monkey, ParseError> {
+        self.monkeys.get(name).ok_or_else(|| {
+            let last = self.lines.len() - 1;
+            ParseError::end_of_line(last, &self.lines[last], &format!("a `{}` monkey", name))
+        })
+    }
+
+    /// Number of an operand of `monkey`, `waiting` being the monkeys
+    /// waiting for it.
+    fn value(
+        &self,
+        monkey: &Monkey,
+        value: &Value,
+        waiting: &mut Vec<&'a str>,
+    ) -> Result<i64, ParseError> {
+        let name = match value {
+            Value::N(n) => return Ok(*n),
+            Value::Monkey(name) => name.as_str(),
+        };
+        let Some((&name, other)) = self.monkeys.get_key_value(name) else {
+            return Err(self.error(monkey, name, "a monkey of the troop"));
+        };
+        if waiting.contains(&name) {
+            return Err(self.error(monkey, name, "a monkey not waiting for itself"));
+        }
+        waiting.push(name);
+        let n = self.yell(other, waiting)?;
+        waiting.pop();
+        Ok(n)
+    }
+
+    fn yell(&self, monkey: &Monkey, waiting: &mut Vec<&'a str>) -> Result<i64, ParseError> {
+        let n = match &monkey.operation {
+            Operation::Yell(value) => self.value(monkey, value, waiting)?,
+            Operation::Add((a, b)) => {
+                self.value(monkey, a, waiting)? + self.value(monkey, b, waiting)?
+            }
+            Operation::Sub((a, b)) => {
+                self.value(monkey, a, waiting)? - self.value(monkey, b, waiting)?
+            }
+            Operation::Mul((a, b)) => {
+                self.value(monkey, a, waiting)? * self.value(monkey, b, waiting)?
+            }
+            Operation::Div((a, b)) => {
+                let a = self.value(monkey, a, waiting)?;
+                match self.value(monkey, b, waiting)? {
+                    0 => return Err(self.error(monkey, &b.to_string(), "a monkey not yelling 0")),
+                    b => a / b,
+                }
+            }
+        };
+        Ok(n)
+    }
+
+    /// Number yelled by the monkey `name`.
+    fn evaluate(&self, name: &'a str) -> Result<i64, ParseError> {
+        self.yell(self.get(name)?, &mut vec![name])
+    }
+
+    /// Whether the number of `value` depends on the one the human yells.
+    fn depends_on_human(&self, value: &Value) -> bool {
+        match value {
+            Value::N(_) => false,
+            Value::Monkey(name) if name == HUMAN => true,
+            Value::Monkey(name) => self.monkeys[name.as_str()]
+                .operation
+                .operands()
+                .iter()
+                .any(|value| self.depends_on_human(value)),
+        }
+    }
+
+    /// What the human has to yell for `value` to be `target`, undoing the
+    /// operations on the way from `value` to the human.
+    fn solve(&self, value: &Value, target: i64) -> Result<i64, ParseError> {
+        let name = match value {
+            Value::Monkey(name) if name == HUMAN => return Ok(target),
+            Value::Monkey(name) => name.as_str(),
+            Value::N(_) => unreachable!(),
+        };
+        let (&name, monkey) = self.monkeys.get_key_value(name).unwrap();
+        let operation = &monkey.operation;
+        let (a, b) = match operation {
+            Operation::Yell(value) => return self.solve(value, target),
+            Operation::Add(operands)
+            | Operation::Sub(operands)
+            | Operation::Mul(operands)
+            | Operation::Div(operands) => operands,
+        };
+        let mut waiting = vec![name];
+        // The divisions have to be exact for the human to yell an integer
+        let exact = |a: i64, b: i64| (b != 0 && a % b == 0).then(|| a / b);
+        let (value, target) = if self.depends_on_human(a) {
+            let n = self.value(monkey, b, &mut waiting)?;
+            let target = match operation {
+                Operation::Add(_) => Some(target - n),
+                Operation::Sub(_) => Some(target + n),
+                Operation::Mul(_) => exact(target, n),
+                Operation::Div(_) => Some(target * n),
+                Operation::Yell(_) => unreachable!(),
+            };
+            (a, target)
+        } else {
+            let n = self.value(monkey, a, &mut waiting)?;
+            let target = match operation {
+                Operation::Add(_) => Some(target - n),
+                Operation::Sub(_) => Some(n - target),
+                Operation::Mul(_) => exact(target, n),
+                Operation::Div(_) => exact(n, target),
+                Operation::Yell(_) => unreachable!(),
+            };
+            (b, target)
+        };
+        match target {
+            Some(target) => self.solve(value, target),
+            None => {
+                let line = &self.lines[monkey.index];
+                let (_, operation) = line.split_once(": ").unwrap();
+                Err(ParseError::new(
+                    monkey.index,
+                    line,
+                    operation,
+                    "an exact solution",
+                ))
+            }
+        }
+    }
+}
+
+#[instrument(skip_all, fields(day = 21, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<i64, ParseError> {
+    let troop = Troop::parse(&input)?;
+    let solution = troop.evaluate(ROOT)?;
+    debug!(solution);
+    Ok(solution)
+}
+
+/// The root monkey checks its two numbers are equal, and humn is you.
+#[instrument(skip_all, fields(day = 21, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<i64, ParseError> {
+    let troop = Troop::parse(&input)?;
+    // Report the cycles and the missing monkeys first
+    troop.evaluate(ROOT)?;
+
+    let root = troop.get(ROOT)?;
+    let operands = root.operation.operands();
+    let solution = match operands[..] {
+        [a, b] if troop.depends_on_human(a) && !troop.depends_on_human(b) => {
+            troop.solve(a, troop.value(root, b, &mut vec![ROOT])?)?
+        }
+        [a, b] if troop.depends_on_human(b) && !troop.depends_on_human(a) => {
+            troop.solve(b, troop.value(root, a, &mut vec![ROOT])?)?
+        }
+        _ => {
+            let line = &input[root.index];
+            let (_, operation) = line.split_once(": ").unwrap();
+            return Err(ParseError::new(
+                root.index,
+                line,
+                operation,
+                "two operands, one of them depending on `humn`",
+            ));
+        }
+    };
+    debug!(solution);
+    Ok(solution)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn name(&self) -> &'static str {
+        "Monkey Math"
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_monkey() {
+        assert_eq!(
+            parse_monkey("dbpl: 5"),
+            Ok(("", ("dbpl", Operation::Yell(Value::N(5)))))
+        );
+        assert_eq!(
+            parse_monkey("ptdq: humn - dvpt"),
+            Ok((
+                "",
+                (
+                    "ptdq",
+                    Operation::Sub((
+                        Value::Monkey("humn".to_string()),
+                        Value::Monkey("dvpt".to_string())
+                    ))
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 152);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 301);
+    }
+
+    #[test]
+    fn test_solve() {
+        // humn on the right of a subtraction and of a division
+        let input = parse_input(indoc!(
+            "
+            root: aaaa + bbbb
+            aaaa: cccc - dddd
+            cccc: 30
+            dddd: eeee / humn
+            eeee: 60
+            bbbb: 20
+            humn: 1
+            "
+        ));
+        assert_eq!(part2(input).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_solve_inexact() {
+        let input = parse_input(indoc!(
+            "
+            root: aaaa + bbbb
+            aaaa: humn * cccc
+            cccc: 4
+            bbbb: 10
+            humn: 1
+            "
+        ));
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 2, column 7: unexpected `humn * cccc`, expected an exact solution"
+        );
+        // Dividing by a human yelling 0
+        let input = parse_input(indoc!(
+            "
+            root: aaaa + bbbb
+            aaaa: cccc / humn
+            cccc: 4
+            bbbb: 0
+            humn: 1
+            "
+        ));
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 2, column 7: unexpected `cccc / humn`, expected an exact solution"
+        );
+        // Or by a monkey yelling 0
+        let input = parse_input(indoc!(
+            "
+            root: aaaa + bbbb
+            aaaa: humn * cccc
+            cccc: dddd - dddd
+            dddd: 3
+            bbbb: 6
+            humn: 1
+            "
+        ));
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 2, column 7: unexpected `humn * cccc`, expected an exact solution"
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_monkey() {
+        let input = parse_input(&EXAMPLE.replace("sjmn: drzm * dbpl", "sjmn: drzm ^ dbpl"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 10, column 12: unexpected `^`, expected a number or an operation"
+        );
+        let input = parse_input(&EXAMPLE.replace("dvpt: 3", "dvpt: 3 4"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 6, column 9: unexpected `4`, expected the end of the line"
+        );
+    }
+
+    #[test]
+    fn test_run_missing_monkey() {
+        let input = parse_input(&EXAMPLE.replace("lgvd: ljgn * ptdq", "lgvd: ljgn * ptdr"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 13, column 14: unexpected `ptdr`, expected a monkey of the troop"
+        );
+        let input = parse_input(&EXAMPLE.replace("root:", "toor:"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 15, column 9: unexpected end of line, expected a `root` monkey"
+        );
+    }
+
+    #[test]
+    fn test_run_cycle() {
+        let input = parse_input(&EXAMPLE.replace("hmdt: 32", "hmdt: sjmn + zczc"));
+        assert_eq!(
+            part1(input.clone()).unwrap_err().to_string(),
+            "line 15, column 7: unexpected `sjmn`, expected a monkey not waiting for itself"
+        );
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 15, column 7: unexpected `sjmn`, expected a monkey not waiting for itself"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day21.example();
+        assert_eq!(Day21.part1(example).unwrap().to_string(), "152");
+        assert_eq!(Day21.part2(example).unwrap().to_string(), "301");
+    }
+}
@@ -0,0 +1,538 @@
+//! Day 22: Monkey Map.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, Coord3, Direction, Grid, ParseError, Solution, SolveError};
+use indoc::indoc;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+            ...#
+            .#..
+            #...
+            ....
+    ...#.......#
+    ........#...
+    ..#....#....
+    ..........#.
+            ...#....
+            .....#..
+            .#......
+            ......#.
+
+    10R5L5R10L4R5L5
+    "
+);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    /// Outside of the board.
+    Void,
+    Open,
+    Wall,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Void => ' ',
+            Cell::Open => '.',
+            Cell::Wall => '#',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Step {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+/// Value of a facing in the password.
+fn facing_value(facing: Direction) -> usize {
+    match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+fn is_void(map: &Grid<Cell>, coord: Coord) -> bool {
+    matches!(map.get_cell(coord), None | Some(Cell::Void))
+}
+
+/// Where leaving a tile towards a facing leads, before checking for walls.
+trait Wrap {
+    fn next(&self, map: &Grid<Cell>, coord: Coord, facing: Direction) -> (Coord, Direction);
+}
+
+/// Part 1: leaving the board comes back on the other side of its row or
+/// column.
+struct Flat;
+
+impl Wrap for Flat {
+    fn next(&self, map: &Grid<Cell>, coord: Coord, facing: Direction) -> (Coord, Direction) {
+        let next = coord + facing.vector();
+        if !is_void(map, next) {
+            return (next, facing);
+        }
+        let back = facing.reverse().vector();
+        let mut coord = coord;
+        while !is_void(map, coord + back) {
+            coord += back;
+        }
+        (coord, facing)
+    }
+}
+
+/// Orientation of a face once folded, as unit vectors: its outward normal
+/// and the directions of the right and the bottom of the map.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    normal: Coord3,
+    right: Coord3,
+    down: Coord3,
+}
+
+impl Face {
+    fn direction(&self, facing: Direction) -> Coord3 {
+        match facing {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Up => -self.down,
+        }
+    }
+
+    /// The neighbour face in the net towards `facing`, folded by 90°.
+    fn fold(&self, facing: Direction) -> Face {
+        let Face {
+            normal,
+            right,
+            down,
+        } = *self;
+        match facing {
+            Direction::Right => Face {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Direction::Left => Face {
+                normal: -right,
+                right: normal,
+                down,
+            },
+            Direction::Down => Face {
+                normal: down,
+                right,
+                down: -normal,
+            },
+            Direction::Up => Face {
+                normal: -down,
+                right,
+                down: normal,
+            },
+        }
+    }
+}
+
+/// Part 2: the net of the map folded into a cube with edges of `size`
+/// tiles, with faces as tiles of the net.
+///
+/// Tiles of a face are placed on the cube at doubled coordinates, so that
+/// the center of the cube is the origin and every tile has integer ones.
+struct Cube {
+    size: isize,
+    faces: HashMap<Coord, Face>,
+    by_normal: HashMap<Coord3, Coord>,
+}
+
+impl Cube {
+    fn fold(map: &Grid<Cell>) -> Option<Self> {
+        let tiles = map.coords().filter(|coord| !is_void(map, *coord)).count();
+        let size = (1..=map.width.max(map.height))
+            .find(|size| 6 * size * size >= tiles)
+            .filter(|size| 6 * size * size == tiles)? as isize;
+
+        let mut faces: HashMap<Coord, Face> = HashMap::new();
+        for y in 0..map.height as isize / size {
+            for x in 0..map.width as isize / size {
+                let face = Coord::new(x, y);
+                let cells = (0..size * size)
+                    .map(|i| Coord::new(x * size + i % size, y * size + i / size))
+                    .filter(|coord| !is_void(map, *coord))
+                    .count() as isize;
+                match cells {
+                    0 => (),
+                    n if n == size * size => {
+                        faces.insert(
+                            face,
+                            Face {
+                                normal: Coord3::new(0, 0, -1),
+                                right: Coord3::new(1, 0, 0),
+                                down: Coord3::new(0, 1, 0),
+                            },
+                        );
+                    }
+                    _ => return None,
+                }
+            }
+        }
+        if faces.len() != 6 {
+            return None;
+        }
+
+        // Fold the faces of the net one after the other, from the first one
+        let first = *faces.keys().min_by_key(|face| (face.y, face.x)).unwrap();
+        let mut by_normal = HashMap::from([(faces[&first].normal, first)]);
+        let mut queue = VecDeque::from([first]);
+        while let Some(tile) = queue.pop_front() {
+            let face = faces[&tile];
+            for facing in Direction::ALL {
+                let next = tile + facing.vector();
+                if !faces.contains_key(&next) || by_normal.values().any(|tile| *tile == next) {
+                    continue;
+                }
+                let folded = face.fold(facing);
+                if by_normal.insert(folded.normal, next).is_some() {
+                    return None;
+                }
+                faces.insert(next, folded);
+                queue.push_back(next);
+            }
+        }
+        // A net in several pieces
+        if by_normal.len() != 6 {
+            return None;
+        }
+        debug!(size, ?faces);
+        Some(Self {
+            size,
+            faces,
+            by_normal,
+        })
+    }
+}
+
+impl Wrap for Cube {
+    fn next(&self, map: &Grid<Cell>, coord: Coord, facing: Direction) -> (Coord, Direction) {
+        let next = coord + facing.vector();
+        if !is_void(map, next) {
+            return (next, facing);
+        }
+        let size = self.size;
+        let tile = Coord::new(coord.x / size, coord.y / size);
+        let face = self.faces[&tile];
+        let position = face.normal * size
+            + face.right * (2 * (coord.x % size) + 1 - size)
+            + face.down * (2 * (coord.y % size) + 1 - size);
+        // Go over the edge, down the next face
+        let direction = face.direction(facing);
+        let position = position + direction - face.normal;
+
+        let next_tile = self.by_normal[&direction];
+        let next_face = self.faces[&next_tile];
+        let x = (position.dot(&next_face.right) + size - 1) / 2;
+        let y = (position.dot(&next_face.down) + size - 1) / 2;
+        let next_facing = *Direction::ALL
+            .iter()
+            .find(|facing| next_face.direction(**facing) == -face.normal)
+            .unwrap();
+        trace!(?coord, ?facing, ?next_tile, x, y, ?next_facing);
+        (
+            Coord::new(next_tile.x * size + x, next_tile.y * size + y),
+            next_facing,
+        )
+    }
+}
+
+/// The map of the board and the path to follow on it.
+struct Board {
+    map: Grid<Cell>,
+    path: Vec<Step>,
+    /// Line of the path, to report maps not folding into a cube.
+    path_index: usize,
+}
+
+fn parse_path(index: usize, line: &str) -> Result<Vec<Step>, ParseError> {
+    const EXPECTED: &str = "a number of tiles, `L` or `R`";
+    let mut path = Vec::new();
+    let mut tiles: Option<usize> = None;
+    for (position, c) in line.char_indices() {
+        let step = match c {
+            '0'..='9' => {
+                let digit = c.to_digit(10).unwrap() as usize;
+                tiles = Some(tiles.unwrap_or(0) * 10 + digit);
+                continue;
+            }
+            'L' => Step::Left,
+            'R' => Step::Right,
+            _ => {
+                let token = &line[position..position + c.len_utf8()];
+                return Err(ParseError::new(index, line, token, EXPECTED));
+            }
+        };
+        if let Some(tiles) = tiles.take() {
+            path.push(Step::Forward(tiles));
+        }
+        path.push(step);
+    }
+    match tiles {
+        Some(tiles) => path.push(Step::Forward(tiles)),
+        None => return Err(ParseError::end_of_line(index, line, "a number of tiles")),
+    }
+    Ok(path)
+}
+
+fn parse_board(input: &[String]) -> Result<Board, ParseError> {
+    let Some(blank) = input.iter().position(|line| line.is_empty()) else {
+        let index = input.len() - 1;
+        return Err(ParseError::end_of_line(
+            index,
+            &input[index],
+            "a blank line then a path",
+        ));
+    };
+    // Pad the rows with void on the right for the map to be rectangular
+    let width = input[..blank]
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    let rows = input[..blank]
+        .iter()
+        .map(|row| format!("{:width$}", row))
+        .collect::<Vec<String>>();
+    let map = Grid::parse(&rows, "`.`, `#` or a space", |c| match c {
+        ' ' => Some(Cell::Void),
+        '.' => Some(Cell::Open),
+        '#' => Some(Cell::Wall),
+        _ => None,
+    })?;
+    trace!(?map);
+
+    let path_index = blank + 1;
+    let Some(line) = input.get(path_index) else {
+        return Err(ParseError::end_of_line(blank, &input[blank], "a path"));
+    };
+    let path = parse_path(path_index, line)?;
+    if let Some(line) = input.get(path_index + 1) {
+        return Err(ParseError::new(
+            path_index + 1,
+            line,
+            line,
+            "the end of the input",
+        ));
+    }
+    Ok(Board {
+        map,
+        path,
+        path_index,
+    })
+}
+
+impl Board {
+    /// Follow the path from the leftmost open tile of the top row, and
+    /// return the final password.
+    fn walk(&self, wrap: &impl Wrap) -> usize {
+        let start = (0..self.map.width as isize)
+            .map(|x| Coord::new(x, 0))
+            .find(|coord| self.map.get_cell(*coord) == Some(&Cell::Open))
+            .unwrap_or_default();
+        let mut coord = start;
+        let mut facing = Direction::Right;
+        for step in &self.path {
+            match step {
+                Step::Left => facing = facing.turn_left(),
+                Step::Right => facing = facing.turn_right(),
+                Step::Forward(tiles) => {
+                    for _ in 0..*tiles {
+                        let (next, next_facing) = wrap.next(&self.map, coord, facing);
+                        if self.map.get_cell(next) == Some(&Cell::Wall) {
+                            break;
+                        }
+                        coord = next;
+                        facing = next_facing;
+                    }
+                }
+            }
+        }
+        debug!(?coord, ?facing);
+        1000 * (coord.y as usize + 1) + 4 * (coord.x as usize + 1) + facing_value(facing)
+    }
+}
+
+#[instrument(skip_all, fields(day = 22, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let board = parse_board(&input)?;
+    let solution = board.walk(&Flat);
+    debug!(solution);
+    Ok(solution)
+}
+
+#[instrument(skip_all, fields(day = 22, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let board = parse_board(&input)?;
+    let Some(cube) = Cube::fold(&board.map) else {
+        // The map is made of every line before the blank one, if any
+        let index = board.path_index.saturating_sub(2);
+        return Err(ParseError::end_of_line(
+            index,
+            &input[index],
+            "a map folding into a cube",
+        ));
+    };
+    let solution = board.walk(&cube);
+    debug!(solution);
+    Ok(solution)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    fn name(&self) -> &'static str {
+        "Monkey Map"
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path(0, "10R5L0").unwrap(),
+            vec![
+                Step::Forward(10),
+                Step::Right,
+                Step::Forward(5),
+                Step::Left,
+                Step::Forward(0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 6032);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 5031);
+    }
+
+    /// Going around the cube in a straight line comes back to the start.
+    fn round_trip(input: &[String]) {
+        let board = parse_board(input).unwrap();
+        let cube = Cube::fold(&board.map).unwrap();
+        let size = cube.size as usize;
+        for (x, y) in (0..board.map.width).flat_map(|x| (0..board.map.height).map(move |y| (x, y)))
+        {
+            let start = Coord::new(x as isize, y as isize);
+            if is_void(&board.map, start) {
+                continue;
+            }
+            for facing in Direction::ALL {
+                let (mut coord, mut heading) = (start, facing);
+                for _ in 0..4 * size {
+                    (coord, heading) = cube.next(&board.map, coord, heading);
+                }
+                assert_eq!((coord, heading), (start, facing));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cube_nets() {
+        round_trip(&parse_input(EXAMPLE));
+        // The layout of the puzzle inputs, then a staircase
+        for net in [" ..\n .\n..\n.\n\n1", "..\n ..\n  ..\n\n1"] {
+            round_trip(&parse_input(net));
+        }
+        for net in [
+            "......\n\n1",
+            "...\n...\n\n1",
+            "..\n..\n\n1",
+            "..\n  ....\n\n1",
+        ] {
+            let board = parse_board(&parse_input(net)).unwrap();
+            assert!(Cube::fold(&board.map).is_none());
+        }
+    }
+
+    #[test]
+    fn test_run_invalid_map() {
+        let input = parse_input(&EXAMPLE.replace("..#....#....", "..#....x...."));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 7, column 8: unexpected `x`, expected `.`, `#` or a space"
+        );
+        let input = parse_input(&EXAMPLE.replace("10R5L5R10L4R5L5", "10R5L5R10L4R5L"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 14, column 15: unexpected end of line, expected a number of tiles"
+        );
+        let input = parse_input(&EXAMPLE.replace("10R5L5R10L4R5L5", "10R5L5R10U4R5L5"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 14, column 10: unexpected `U`, expected a number of tiles, `L` or `R`"
+        );
+    }
+
+    #[test]
+    fn test_run_invalid_cube() {
+        let input = parse_input(&EXAMPLE.replace("        ...#....\n", "        ...#\n"));
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 12, column 17: unexpected end of line, expected a map folding into a cube"
+        );
+        let input = parse_input("\n10\n");
+        assert_eq!(
+            part2(input).unwrap_err().to_string(),
+            "line 1, column 1: unexpected end of line, expected a map folding into a cube"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day22.example();
+        assert_eq!(Day22.part1(example).unwrap().to_string(), "6032");
+        assert_eq!(Day22.part2(example).unwrap().to_string(), "5031");
+    }
+}
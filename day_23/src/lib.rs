@@ -0,0 +1,258 @@
+//! Day 23: Unstable Diffusion.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, ParseError, Solution, SolveError, Vector2};
+use indoc::indoc;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    ....#..
+    ..###.#
+    #...#.#
+    .#...##
+    #.###..
+    ##.#.##
+    .#..#..
+    "
+);
+
+/// Directions an elf looks at in order to propose a move, each with the
+/// three adjacent positions that have to be free: the move first.
+const DIRECTIONS: [[Vector2; 3]; 4] = [
+    [Vector2::N, Vector2::NW, Vector2::NE],
+    [Vector2::S, Vector2::SW, Vector2::SE],
+    [Vector2::W, Vector2::NW, Vector2::SW],
+    [Vector2::E, Vector2::NE, Vector2::SE],
+];
+
+/// The elves on the ground, which grows as they spread out.
+struct Grove {
+    elves: HashSet<Coord>,
+    /// Index of the first direction considered this round.
+    first: usize,
+}
+
+impl Grove {
+    fn parse(input: &[String]) -> Result<Self, ParseError> {
+        let mut elves = HashSet::new();
+        for (index, line) in input.iter().enumerate() {
+            for (x, (position, c)) in line.char_indices().enumerate() {
+                match c {
+                    '#' => {
+                        elves.insert(Coord::new(x as isize, index as isize));
+                    }
+                    '.' => (),
+                    _ => {
+                        let token = &line[position..position + c.len_utf8()];
+                        return Err(ParseError::new(index, line, token, "`#` or `.`"));
+                    }
+                }
+            }
+        }
+        Ok(Self { elves, first: 0 })
+    }
+
+    fn is_alone(&self, elf: Coord) -> bool {
+        Vector2::AROUND
+            .iter()
+            .all(|delta| !self.elves.contains(&(elf + *delta)))
+    }
+
+    fn propose(&self, elf: Coord) -> Option<Coord> {
+        if self.is_alone(elf) {
+            return None;
+        }
+        (0..4)
+            .map(|i| DIRECTIONS[(self.first + i) % 4])
+            .find(|checks| {
+                checks
+                    .iter()
+                    .all(|delta| !self.elves.contains(&(elf + *delta)))
+            })
+            .map(|checks| elf + checks[0])
+    }
+
+    /// Play a round, returning whether any elf moved.
+    fn round(&mut self) -> bool {
+        // Elves proposing each position, `None` when several of them do
+        let mut proposals: HashMap<Coord, Option<Coord>> = HashMap::new();
+        for elf in &self.elves {
+            if let Some(target) = self.propose(*elf) {
+                proposals
+                    .entry(target)
+                    .and_modify(|from| *from = None)
+                    .or_insert(Some(*elf));
+            }
+        }
+        let mut moved = false;
+        for (target, from) in proposals {
+            if let Some(from) = from {
+                self.elves.remove(&from);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.first = (self.first + 1) % 4;
+        moved
+    }
+
+    /// Smallest rectangle holding every elf, as its top left and bottom
+    /// right corners.
+    fn bounds(&self) -> (Coord, Coord) {
+        let min_x = self.elves.iter().map(|elf| elf.x).min().unwrap_or(0);
+        let max_x = self.elves.iter().map(|elf| elf.x).max().unwrap_or(-1);
+        let min_y = self.elves.iter().map(|elf| elf.y).min().unwrap_or(0);
+        let max_y = self.elves.iter().map(|elf| elf.y).max().unwrap_or(-1);
+        (Coord::new(min_x, min_y), Coord::new(max_x, max_y))
+    }
+
+    fn empty_ground(&self) -> usize {
+        let (min, max) = self.bounds();
+        let area = (max.x - min.x + 1) * (max.y - min.y + 1);
+        area as usize - self.elves.len()
+    }
+}
+
+impl fmt::Display for Grove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (min, max) = self.bounds();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let c = if self.elves.contains(&Coord::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[instrument(skip_all, fields(day = 23, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let mut grove = Grove::parse(&input)?;
+    for _ in 0..10 {
+        grove.round();
+    }
+    trace!("grove:\n{}", grove);
+    let solution = grove.empty_ground();
+    debug!(solution);
+    Ok(solution)
+}
+
+#[instrument(skip_all, fields(day = 23, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let mut grove = Grove::parse(&input)?;
+    let mut solution = 1;
+    while grove.round() {
+        solution += 1;
+    }
+    debug!(solution);
+    Ok(solution)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn name(&self) -> &'static str {
+        "Unstable Diffusion"
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_round() {
+        let input = parse_input(indoc!(
+            "
+            .....
+            ..##.
+            ..#..
+            .....
+            ..##.
+            .....
+            "
+        ));
+        let mut grove = Grove::parse(&input).unwrap();
+        for _ in 0..3 {
+            assert!(grove.round());
+        }
+        assert_eq!(
+            grove.to_string(),
+            indoc!(
+                "
+                ..#..
+                ....#
+                #....
+                ....#
+                .....
+                ..#..
+                "
+            )
+        );
+        assert!(!grove.round());
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 110);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 20);
+    }
+
+    #[test]
+    fn test_run_invalid_ground() {
+        let input = parse_input(&EXAMPLE.replace("#.###..", "#.#x#.."));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 5, column 4: unexpected `x`, expected `#` or `.`"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day23.example();
+        assert_eq!(Day23.part1(example).unwrap().to_string(), "110");
+        assert_eq!(Day23.part2(example).unwrap().to_string(), "20");
+    }
+}
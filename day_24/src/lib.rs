@@ -0,0 +1,349 @@
+//! Day 24: Blizzard Basin.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, Coord, Direction, Grid, ParseError, Solution, SolveError, Vector2};
+use indoc::indoc;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use tracing::{debug, instrument, trace};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    #.######
+    #>>.<^<#
+    #.<..<<#
+    #>v.><>#
+    #<^v^^>#
+    ######.#
+    "
+);
+
+/// A ground tile of the valley at minute 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Clear,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::Clear => '.',
+            Cell::Up => '^',
+            Cell::Down => 'v',
+            Cell::Left => '<',
+            Cell::Right => '>',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The ground of the valley inside its walls, the entrance being just above
+/// it and the exit just below.
+struct Valley {
+    map: Grid<Cell>,
+    entrance: Coord,
+    exit: Coord,
+    /// Number of minutes after which the blizzards are back where they
+    /// started.
+    period: usize,
+}
+
+/// Column of the single gap of a wall line.
+fn parse_wall(index: usize, line: &str, expected: &str) -> Result<isize, ParseError> {
+    let mut gaps = line.char_indices().filter(|(_, c)| *c != '#');
+    let Some((position, gap)) = gaps.next() else {
+        return Err(ParseError::end_of_line(index, line, expected));
+    };
+    let token = &line[position..position + gap.len_utf8()];
+    if gap != '.' {
+        return Err(ParseError::new(index, line, token, expected));
+    }
+    if let Some((position, c)) = gaps.next() {
+        let token = &line[position..position + c.len_utf8()];
+        return Err(ParseError::new(index, line, token, "`#`"));
+    }
+    // Columns of the map start after the left wall
+    Ok(line[..position].chars().count() as isize - 1)
+}
+
+fn parse_valley(input: &[String]) -> Result<Valley, ParseError> {
+    const EXPECTED: &str = "`.`, `<`, `>`, `^` or `v`";
+    if input.len() < 3 {
+        let index = input.len() - 1;
+        return Err(ParseError::end_of_line(index, &input[index], "a wall line"));
+    }
+    let width = input[0].chars().count().saturating_sub(2);
+    if width == 0 {
+        return Err(ParseError::end_of_line(0, &input[0], "a wider valley"));
+    }
+    let height = input.len() - 2;
+    let entrance = Coord::new(parse_wall(0, &input[0], "an entrance `.`")?, -1);
+    let last = input.len() - 1;
+    let exit = Coord::new(
+        parse_wall(last, &input[last], "an exit `.`")?,
+        height as isize,
+    );
+
+    let mut map = Grid::new(width, height);
+    for (index, line) in input.iter().enumerate().take(last).skip(1) {
+        let mut chars = line.char_indices();
+        for x in 0..width + 2 {
+            let Some((position, c)) = chars.next() else {
+                let expected = if x == width + 1 {
+                    "a wall `#`"
+                } else {
+                    EXPECTED
+                };
+                return Err(ParseError::end_of_line(index, line, expected));
+            };
+            let token = &line[position..position + c.len_utf8()];
+            if x == 0 || x == width + 1 {
+                if c != '#' {
+                    return Err(ParseError::new(index, line, token, "a wall `#`"));
+                }
+                continue;
+            }
+            let cell = match c {
+                '.' => Cell::Clear,
+                '^' => Cell::Up,
+                'v' => Cell::Down,
+                '<' => Cell::Left,
+                '>' => Cell::Right,
+                _ => return Err(ParseError::new(index, line, token, EXPECTED)),
+            };
+            map.push(cell);
+        }
+        if let Some((position, c)) = chars.next() {
+            let token = &line[position..position + c.len_utf8()];
+            return Err(ParseError::new(index, line, token, "the end of the line"));
+        }
+    }
+    trace!(?map);
+
+    let period = width * height / gcd(width, height);
+    Ok(Valley {
+        map,
+        entrance,
+        exit,
+        period,
+    })
+}
+
+impl Valley {
+    /// Whether a blizzard is on the ground tile `coord` at `minute`: the
+    /// blizzards which could be there started on its row or its column.
+    fn is_blizzard(&self, coord: Coord, minute: usize) -> bool {
+        let width = self.map.width as isize;
+        let height = self.map.height as isize;
+        let minute = minute as isize;
+        let at = |x: isize, y: isize| self.map.get_cell(Coord::new(x, y)).unwrap();
+        *at((coord.x - minute).rem_euclid(width), coord.y) == Cell::Right
+            || *at((coord.x + minute).rem_euclid(width), coord.y) == Cell::Left
+            || *at(coord.x, (coord.y - minute).rem_euclid(height)) == Cell::Down
+            || *at(coord.x, (coord.y + minute).rem_euclid(height)) == Cell::Up
+    }
+
+    fn is_free(&self, coord: Coord, minute: usize) -> bool {
+        coord == self.entrance
+            || coord == self.exit
+            || (self.map.bound(&coord) && !self.is_blizzard(coord, minute))
+    }
+
+    /// Minute of the earliest arrival at `to` leaving `from` at `minute`,
+    /// searching breadth first through positions at each minute.
+    fn cross(&self, from: Coord, to: Coord, minute: usize) -> Option<usize> {
+        // Waiting, or moving in any direction
+        let moves = Direction::ALL
+            .iter()
+            .map(|direction| direction.vector())
+            .chain([Vector2::default()])
+            .collect::<Vec<Vector2>>();
+        let mut queue = VecDeque::from([(from, minute)]);
+        // Blizzards repeat, so the same position at the same point of their
+        // period is the same state
+        let mut seen = HashSet::from([(from, minute % self.period)]);
+        while let Some((coord, minute)) = queue.pop_front() {
+            for step in &moves {
+                let next = coord + *step;
+                if next == to {
+                    return Some(minute + 1);
+                }
+                if self.is_free(next, minute + 1) && seen.insert((next, (minute + 1) % self.period))
+                {
+                    queue.push_back((next, minute + 1));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Error for a valley without any way through its blizzards.
+fn blocked(input: &[String]) -> ParseError {
+    let last = input.len() - 1;
+    ParseError::end_of_line(
+        last,
+        &input[last],
+        "a valley with a way through the blizzards",
+    )
+}
+
+#[instrument(skip_all, fields(day = 24, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<usize, ParseError> {
+    let valley = parse_valley(&input)?;
+    let solution = valley
+        .cross(valley.entrance, valley.exit, 0)
+        .ok_or_else(|| blocked(&input))?;
+    debug!(solution);
+    Ok(solution)
+}
+
+/// Go to the exit, back to the entrance for the snacks, then to the exit
+/// again.
+#[instrument(skip_all, fields(day = 24, part = 2))]
+pub fn part2(input: Vec<String>) -> Result<usize, ParseError> {
+    let valley = parse_valley(&input)?;
+    let (entrance, exit) = (valley.entrance, valley.exit);
+    let solution = valley
+        .cross(entrance, exit, 0)
+        .and_then(|minute| valley.cross(exit, entrance, minute))
+        .and_then(|minute| valley.cross(entrance, exit, minute))
+        .ok_or_else(|| blocked(&input))?;
+    debug!(solution);
+    Ok(solution)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn name(&self) -> &'static str {
+        "Blizzard Basin"
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part2(parse_input(input))?))
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_is_blizzard() {
+        let valley = parse_valley(&parse_input(EXAMPLE)).unwrap();
+        assert_eq!(valley.period, 12);
+        // Minute 1 of the example
+        //   #.>3.<.#
+        //   #<..<<.#
+        assert!(!valley.is_blizzard(Coord::new(0, 0), 1));
+        assert!(valley.is_blizzard(Coord::new(1, 0), 1));
+        assert!(!valley.is_blizzard(Coord::new(3, 0), 1));
+        assert!(valley.is_blizzard(Coord::new(0, 1), 1));
+        assert!(!valley.is_blizzard(Coord::new(1, 1), 1));
+        assert!(valley.is_blizzard(Coord::new(1, 0), 13));
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = parse_input(EXAMPLE);
+        let answer = part2(input).unwrap();
+        assert_eq!(answer, 54);
+    }
+
+    #[test]
+    fn test_run_invalid_valley() {
+        let input = parse_input(&EXAMPLE.replace("#>v.><>#", "#>v.x<>#"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 4, column 5: unexpected `x`, expected `.`, `<`, `>`, `^` or `v`"
+        );
+        let input = parse_input(&EXAMPLE.replace("#.<..<<#", "#.<..<<."));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 3, column 8: unexpected `.`, expected a wall `#`"
+        );
+        let input = parse_input(&EXAMPLE.replace("#.######", "########"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 1, column 9: unexpected end of line, expected an entrance `.`"
+        );
+        let input = parse_input(&EXAMPLE.replace("######.#", "#.####.#"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 6, column 7: unexpected `.`, expected `#`"
+        );
+    }
+
+    #[test]
+    fn test_run_blocked() {
+        let input = parse_input(indoc!(
+            "
+            #.###
+            #>>.#
+            #.<.#
+            ###.#
+            "
+        ));
+        assert!(part1(input).is_ok());
+        let input = parse_input(indoc!(
+            "
+            #.#
+            #<#
+            #>#
+            #.#
+            "
+        ));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 4, column 4: unexpected end of line, expected a valley with a way through the blizzards"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day24.example();
+        assert_eq!(Day24.part1(example).unwrap().to_string(), "18");
+        assert_eq!(Day24.part2(example).unwrap().to_string(), "54");
+    }
+}
@@ -0,0 +1,116 @@
+//! Day 25: Full of Hot Air.
+//!
+//! The last day has a single part, the second star being given for the
+//! 49 others.
+
+pub use aoc_common::parse_input;
+use aoc_common::{Answer, ParseError, Snafu, Solution, SolveError};
+use indoc::indoc;
+use tracing::{debug, instrument};
+
+pub const EXAMPLE: &str = indoc!(
+    "
+    1=-0-2
+    12111
+    2=0=
+    21
+    2=01
+    111
+    20012
+    112
+    1=-1=
+    1-12
+    12
+    1=
+    122
+    "
+);
+
+/// Sum of the fuel requirements, as the SNAFU number to enter on the
+/// console.
+#[instrument(skip_all, fields(day = 25, part = 1))]
+pub fn part1(input: Vec<String>) -> Result<Snafu, ParseError> {
+    let solution = input
+        .iter()
+        .enumerate()
+        .try_fold(Snafu::default(), |sum, (index, line)| {
+            sum.checked_add(Snafu::parse_token(index, line, line)?)
+                .ok_or_else(|| ParseError::new(index, line, line, "a sum within 64 bits"))
+        })?;
+    debug!(%solution);
+    Ok(solution)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn name(&self) -> &'static str {
+        "Full of Hot Air"
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn example(&self) -> &'static str {
+        EXAMPLE
+    }
+
+    fn part1(&self, input: &str) -> Result<Answer, SolveError> {
+        Ok(Box::new(part1(parse_input(input))?))
+    }
+
+    /// The last day only has one part, its second star is given for the 49 others.
+    fn part2(&self, _input: &str) -> Result<Answer, SolveError> {
+        Err(SolveError::NoSuchPart(2))
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        &[1]
+    }
+}
+
+#[allow(unused_imports)]
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn test_fake() {
+        assert_eq!(1, 1);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = parse_input(EXAMPLE);
+        let answer = part1(input).unwrap();
+        assert_eq!(answer, Snafu(4890));
+        assert_eq!(answer.to_string(), "2=-1=0");
+    }
+
+    #[test]
+    fn test_run_invalid_number() {
+        let input = parse_input(&EXAMPLE.replace("2=01", "2=o1"));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 5, column 3: unexpected `o`, expected a SNAFU digit `=`, `-`, `0`, `1` or `2`"
+        );
+        let max = Snafu(i64::MAX).to_string();
+        let input = parse_input(&format!("{}\n1\n", max));
+        assert_eq!(
+            part1(input).unwrap_err().to_string(),
+            "line 2, column 1: unexpected `1`, expected a sum within 64 bits"
+        );
+    }
+
+    #[test]
+    fn test_solution() {
+        let example = Day25.example();
+        assert_eq!(Day25.part1(example).unwrap().to_string(), "2=-1=0");
+        assert!(Day25.solve(2, example).is_none());
+        assert_eq!(Day25.part2(example).err(), Some(SolveError::NoSuchPart(2)));
+    }
+}